@@ -1,22 +1,46 @@
 use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
     io,
-    path::PathBuf,
-    sync::mpsc::{
-        self,
-        Receiver,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        mpsc::{
+            self,
+            Receiver,
+            Sender,
+        },
+        Arc,
+        Condvar,
+        Mutex,
     },
     thread::{
         self,
         JoinHandle,
     },
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
 use crate::{
+    fs::{
+        modified_time,
+        read_listing,
+        DirListing,
+        DirWalker,
+    },
     target::SweepableTarget,
-    DirEntryEx,
-    DirWalker,
+    EntryKind,
+    ScanIndex,
     Sweeper,
     SweeperError,
+    TargetRecord,
 };
 
 pub struct CrewOptions {
@@ -25,6 +49,20 @@ pub struct CrewOptions {
     pub search_recursively: bool,
 
     pub report_consumer: Box<dyn CrewReportConsumer + Send>,
+
+    /// Index of the previous scan.
+    /// Targets from the index will be revalidated and reported first and
+    /// directories which did not change since the previous scan will not be read again.
+    /// After a complete scan the index will be updated with the new results.
+    pub scan_index: Option<Arc<Mutex<ScanIndex>>>,
+
+    /// Keep watching the root directory for changes after the initial scan.
+    /// New targets will be send, removed and changed targets will be reported.
+    pub watch: bool,
+
+    /// Interval of full rescans if the file system can not be watched,
+    /// e.g. because the watch limit has been reached.
+    pub watch_rescan_interval: Duration,
 }
 
 impl Default for CrewOptions {
@@ -32,6 +70,9 @@ impl Default for CrewOptions {
         Self {
             search_recursively: false,
             report_consumer: Box::new(VoidCrewReportConsumer),
+            scan_index: None,
+            watch: false,
+            watch_rescan_interval: Duration::from_secs(60),
         }
     }
 }
@@ -45,13 +86,199 @@ impl CrewReportConsumer for VoidCrewReportConsumer {
     fn consume_report(&mut self, _report: CrewReport) {}
 }
 
+/// Reason why the crew did not descend into a directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The directory has been identified as a target and
+    /// [CrewOptions::search_recursively] is disabled.
+    TargetIdentified,
+
+    /// The directory is a symbolic link which will not be followed.
+    Symlink,
+}
+
+/// Statistics about a crew run
+#[derive(Debug, Default, Clone)]
+pub struct CrewStatistics {
+    /// Number of directories which have been read
+    pub dirs_visited: u64,
+
+    /// Number of directory entries which have been inspected by the sweepers
+    pub entries_inspected: u64,
+
+    /// Number of targets identified
+    pub targets_found: u64,
+
+    /// Number of file system and sweeper errors
+    pub errors: u64,
+
+    /// Time elapsed since the crew has been started
+    pub elapsed: Duration,
+}
+
 pub enum CrewReport {
     StatusInspecting(PathBuf),
+    DirectoryEntered(PathBuf),
+    DirectorySkipped {
+        path: PathBuf,
+        reason: SkipReason,
+    },
+    TargetFound {
+        sweeper: String,
+        name: String,
+        path: PathBuf,
+    },
     ErrorSweeper {
         sweeper: String,
+        path: PathBuf,
         error: SweeperError,
     },
-    ErrorFs(io::Error),
+    ErrorFs {
+        path: PathBuf,
+        error: io::Error,
+    },
+
+    /// The crew finished searching.
+    /// This is the last report emitted by the crew, even if the search has been cancelled,
+    /// unless the crew keeps watching for changes.
+    ScanFinished {
+        statistics: CrewStatistics,
+        cancelled: bool,
+    },
+
+    /// The crew started watching for changes.
+    /// If `polling` is set, the file system can not be watched and
+    /// the crew periodically rescans the root directory instead.
+    Watching {
+        polling: bool,
+    },
+
+    /// A previously found target no longer exists.
+    TargetRemoved(PathBuf),
+
+    /// The contents of a previously found target changed.
+    TargetChanged(PathBuf),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CrewState {
+    Running,
+    Paused,
+    Cancelled,
+}
+
+pub(crate) struct CrewControl {
+    state: Mutex<CrewState>,
+    state_changed: Condvar,
+}
+
+impl CrewControl {
+    fn new() -> Self {
+        Self {
+            state: Mutex::new(CrewState::Running),
+            state_changed: Condvar::new(),
+        }
+    }
+
+    fn set_state(&self, new_state: CrewState) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        if *state == CrewState::Cancelled {
+            /* a cancelled crew can not be resumed */
+            return;
+        }
+
+        *state = new_state;
+        self.state_changed.notify_all();
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.state
+            .lock()
+            .map_or(true, |state| *state == CrewState::Cancelled)
+    }
+
+    /// Block while the crew is paused.
+    /// Returns `false` if the crew has been cancelled and should stop working.
+    fn wait_runnable(&self) -> bool {
+        let Ok(state) = self.state.lock() else {
+            return false;
+        };
+
+        let state = match self
+            .state_changed
+            .wait_while(state, |state| *state == CrewState::Paused)
+        {
+            Ok(state) => state,
+            Err(_) => return false,
+        };
+
+        *state == CrewState::Running
+    }
+}
+
+/// Handle to control a running sweeper crew.
+/// The control state is checked by the crew between each directory entry.
+///
+/// Dropping the handle cancels the crew.
+pub struct CrewHandle {
+    control: Arc<CrewControl>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl CrewHandle {
+    /// Stop searching for new targets.
+    /// Note: A cancelled crew can not be resumed.
+    pub fn cancel(&self) {
+        self.control.set_state(CrewState::Cancelled);
+    }
+
+    /// Pause the search until [CrewHandle::resume] gets called.
+    pub fn pause(&self) {
+        self.control.set_state(CrewState::Paused);
+    }
+
+    pub fn resume(&self) {
+        self.control.set_state(CrewState::Running);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.control
+            .state
+            .lock()
+            .is_ok_and(|state| *state == CrewState::Paused)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.control.is_cancelled()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        match &self.thread {
+            Some(thread) => thread.is_finished(),
+            None => true,
+        }
+    }
+
+    /// Cancel the crew and wait for the search thread to exit.
+    pub fn cancel_and_join(mut self) {
+        self.cancel();
+
+        let Some(thread) = self.thread.take() else {
+            return;
+        };
+        if thread.join().is_err() {
+            log::warn!("Sweeper crew thread panicked");
+        }
+    }
+}
+
+impl Drop for CrewHandle {
+    fn drop(&mut self) {
+        self.cancel();
+    }
 }
 
 pub struct SweeperCrew {
@@ -76,78 +303,653 @@ impl SweeperCrew {
     pub fn execute(
         self,
         root_directory: PathBuf,
-        mut options: CrewOptions,
-    ) -> (JoinHandle<()>, Receiver<Box<dyn SweepableTarget>>) {
+        options: CrewOptions,
+    ) -> (CrewHandle, Receiver<Box<dyn SweepableTarget>>) {
         let (tx, rx) = mpsc::channel();
-        let handle = thread::spawn(move || {
-            let mut dir_walker = DirWalker::new();
-            if let Err(error) = dir_walker.insert_entries(&root_directory) {
-                log::warn!("Failed to read root dir: {:#}", error);
-                options
-                    .report_consumer
-                    .consume_report(CrewReport::ErrorFs(error));
-            }
+        let control = Arc::new(CrewControl::new());
+        let thread_control = control.clone();
+        let thread = thread::spawn(move || {
+            let mut run = CrewRun {
+                members: self.members,
+                options,
+                tx,
+                control: thread_control,
+
+                time_started: Instant::now(),
+                statistics: Default::default(),
+                sent_targets: Default::default(),
+                target_paths: Default::default(),
+                global_targets: Default::default(),
+                target_files: Default::default(),
+                scan_found_targets: Default::default(),
+                scan_entered_dirs: Default::default(),
+            };
+            run.execute(&root_directory);
+        });
+
+        (
+            CrewHandle {
+                control,
+                thread: Some(thread),
+            },
+            rx,
+        )
+    }
+}
+
+/// The crew stopped as it has been cancelled or the receiving end has been closed
+pub(crate) struct CrewAborted;
+
+/// State of the crew search thread
+pub(crate) struct CrewRun {
+    members: Vec<Box<dyn Sweeper>>,
+    pub(crate) options: CrewOptions,
+    tx: Sender<Box<dyn SweepableTarget>>,
+    pub(crate) control: Arc<CrewControl>,
+
+    time_started: Instant,
+    statistics: CrewStatistics,
+
+    /// Targets which already have been send, identified by sweeper name and path.
+    /// Every path is only sent once, even if multiple sweepers identified it.
+    pub(crate) sent_targets: HashSet<(String, PathBuf)>,
+
+    /// Paths of all sent targets
+    pub(crate) target_paths: HashSet<PathBuf>,
 
-            'search_loop: while let Some(item) = dir_walker.next_item() {
-                let item_path = item.path();
-                let mut target_found = false;
-
-                options
-                    .report_consumer
-                    .consume_report(CrewReport::StatusInspecting(item_path.clone()));
-
-                for sweeper in &self.members {
-                    let targets = match sweeper.identify_targets(&item_path) {
-                        Ok(targets) => targets,
-                        Err(error) => {
-                            log::warn!(
-                                "Sweeper {} failed for {}: {:#}",
-                                sweeper.name(),
-                                item_path.display(),
-                                error
-                            );
-                            options
-                                .report_consumer
-                                .consume_report(CrewReport::ErrorSweeper {
-                                    error,
-                                    sweeper: sweeper.name().to_string(),
-                                });
-                            continue;
-                        }
-                    };
-
-                    for target in targets {
-                        log::trace!(
-                            "Identified new target {} at {} by {}",
-                            target.name(),
-                            target.path().display(),
-                            sweeper.name()
-                        );
-                        target_found = true;
-
-                        if tx.send(target).is_err() {
-                            /* Abort search */
-                            log::debug!("Aborting search as receiving end has been closed");
-                            break 'search_loop;
-                        }
+    /// Paths of all sent global targets
+    pub(crate) global_targets: HashSet<PathBuf>,
+
+    /// Files of all sent file set targets mapped to the path of their target
+    pub(crate) target_files: HashMap<PathBuf, PathBuf>,
+
+    /// Targets identified during the current scan
+    pub(crate) scan_found_targets: HashSet<(String, PathBuf)>,
+
+    /// Directories read during the current scan
+    pub(crate) scan_entered_dirs: Vec<PathBuf>,
+}
+
+impl CrewRun {
+    fn execute(&mut self, root_directory: &Path) {
+        let previous_index = self
+            .options
+            .scan_index
+            .as_ref()
+            .and_then(|index| index.lock().ok().map(|mut index| index.take_listings()));
+        let mut current_index = previous_index
+            .as_ref()
+            .map(|index| ScanIndex::new(index.root().to_owned()));
+
+        let mut result = Ok(());
+        if let Some(previous_index) = &previous_index {
+            result = self.revalidate_index(previous_index);
+        }
+        if result.is_ok() {
+            result = self.identify_global_targets(root_directory);
+        }
+        if result.is_ok() {
+            result = self.scan(
+                root_directory,
+                previous_index.as_ref(),
+                current_index.as_mut(),
+            );
+        }
+        if result.is_ok() {
+            result = self.finish_scan(root_directory);
+        }
+
+        let cancelled = result.is_err();
+        if let (Some(scan_index), Some(current_index)) = (&self.options.scan_index, current_index) {
+            if let Ok(mut scan_index) = scan_index.lock() {
+                if cancelled {
+                    log::debug!("Discarding scan index results as the scan has been cancelled");
+                    if let Some(previous_index) = previous_index {
+                        scan_index.replace_with(previous_index);
                     }
+                } else {
+                    scan_index.replace_with(current_index);
                 }
+            }
+        }
 
-                if item.is_dir() && (options.search_recursively || !target_found) {
-                    if let Err(error) = dir_walker.insert_entries(&item_path) {
-                        log::warn!(
-                            "Failed to read directory {}: {:#}",
-                            item_path.display(),
-                            error
-                        );
-                        options
-                            .report_consumer
-                            .consume_report(CrewReport::ErrorFs(error));
+        self.statistics.elapsed = self.time_started.elapsed();
+        log::debug!(
+            "Sweeper crew finished (cancelled: {}): {:?}",
+            cancelled,
+            self.statistics
+        );
+        self.report(CrewReport::ScanFinished {
+            statistics: self.statistics.clone(),
+            cancelled,
+        });
+
+        if !cancelled && self.options.watch {
+            self.watch(root_directory);
+        }
+    }
+
+    pub(crate) fn report(&mut self, report: CrewReport) {
+        self.options.report_consumer.consume_report(report);
+    }
+
+    /// Block while the crew is paused and abort if the crew has been cancelled.
+    pub(crate) fn check_runnable(&self) -> Result<(), CrewAborted> {
+        if self.control.wait_runnable() {
+            Ok(())
+        } else {
+            log::debug!("Aborting search as the crew has been cancelled");
+            Err(CrewAborted)
+        }
+    }
+
+    fn send_target(
+        &mut self,
+        sweeper: String,
+        target: Box<dyn SweepableTarget>,
+    ) -> Result<(), CrewAborted> {
+        if self.target_files.contains_key(target.path()) {
+            /* already part of a file set, e.g. the module outputs of a reactor */
+            return Ok(());
+        }
+        if !self.target_paths.insert(target.path().to_owned()) {
+            /* target has already been send, e.g. revalidated from the index or identified by another sweeper */
+            return Ok(());
+        }
+        self.sent_targets
+            .insert((sweeper.clone(), target.path().to_owned()));
+        if target.is_global() {
+            self.global_targets.insert(target.path().to_owned());
+        }
+        for file in target.files() {
+            self.target_files
+                .insert(file.clone(), target.path().to_owned());
+        }
+
+        self.statistics.targets_found += 1;
+        self.report(CrewReport::TargetFound {
+            sweeper,
+            name: target.name().to_string(),
+            path: target.path().to_owned(),
+        });
+
+        if self.tx.send(target).is_err() {
+            log::debug!("Aborting search as receiving end has been closed");
+            return Err(CrewAborted);
+        }
+
+        Ok(())
+    }
+
+    fn revalidate_index(&mut self, previous_index: &ScanIndex) -> Result<(), CrewAborted> {
+        /* targets are identified again from the inspected path and matched by their path */
+        let mut inspections = Vec::<(&str, &Path, HashSet<&Path>)>::new();
+        for record in previous_index.targets() {
+            let inspection = inspections.iter_mut().find(|(sweeper, path, _)| {
+                *sweeper == record.sweeper && *path == record.inspected_path
+            });
+            match inspection {
+                Some((_, _, target_paths)) => {
+                    target_paths.insert(&record.path);
+                }
+                None => inspections.push((
+                    &record.sweeper,
+                    &record.inspected_path,
+                    HashSet::from([record.path.as_path()]),
+                )),
+            }
+        }
+
+        for (sweeper_name, inspected_path, target_paths) in inspections {
+            self.check_runnable()?;
+
+            let Some(sweeper) = self
+                .members
+                .iter()
+                .find(|sweeper| sweeper.name() == sweeper_name)
+            else {
+                continue;
+            };
+
+            /* errors will be reported when the path is visited again */
+            let Ok(targets) = sweeper.identify_targets(inspected_path) else {
+                continue;
+            };
+
+            let sweeper = sweeper.name().to_string();
+            for target in targets {
+                if !target_paths.contains(target.path()) {
+                    continue;
+                }
+
+                log::trace!(
+                    "Revalidated target {} at {} by {}",
+                    target.name(),
+                    target.path().display(),
+                    sweeper
+                );
+                self.send_target(sweeper.clone(), target)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Let all sweepers report their global targets.
+    /// Global targets are not part of the scan index as they do not depend on the root directory.
+    pub(crate) fn identify_global_targets(
+        &mut self,
+        root_directory: &Path,
+    ) -> Result<(), CrewAborted> {
+        self.identify_scan_independent_targets(root_directory, "global targets", |sweeper| {
+            sweeper.identify_global_targets()
+        })
+    }
+
+    /// Let all sweepers report the targets resulting from the completed scan.
+    /// These targets are not part of the scan index as they depend on the whole scan.
+    pub(crate) fn finish_scan(&mut self, root_directory: &Path) -> Result<(), CrewAborted> {
+        self.identify_scan_independent_targets(root_directory, "scan targets", |sweeper| {
+            sweeper.finish_scan()
+        })
+    }
+
+    fn identify_scan_independent_targets(
+        &mut self,
+        root_directory: &Path,
+        description: &str,
+        identify: impl Fn(&dyn Sweeper) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError>,
+    ) -> Result<(), CrewAborted> {
+        let mut identified_targets = Vec::new();
+        let mut sweeper_errors = Vec::new();
+        for sweeper in &self.members {
+            match identify(sweeper.as_ref()) {
+                Ok(targets) => identified_targets.extend(
+                    targets
+                        .into_iter()
+                        .map(|target| (sweeper.name().to_string(), target)),
+                ),
+                Err(error) => {
+                    log::warn!(
+                        "Sweeper {} failed to identify {}: {:#}",
+                        sweeper.name(),
+                        description,
+                        error
+                    );
+                    sweeper_errors.push((sweeper.name().to_string(), error));
+                }
+            }
+        }
+
+        for (sweeper, error) in sweeper_errors {
+            self.statistics.errors += 1;
+            self.report(CrewReport::ErrorSweeper {
+                error,
+                path: root_directory.to_owned(),
+                sweeper,
+            });
+        }
+
+        for (sweeper, target) in identified_targets {
+            log::trace!(
+                "Identified {} {} at {} by {}",
+                description,
+                target.name(),
+                target.path().display(),
+                sweeper
+            );
+
+            self.scan_found_targets
+                .insert((sweeper.clone(), target.path().to_owned()));
+            self.send_target(sweeper, target)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the path has been sent as target or is a file of a sent target.
+    pub(crate) fn is_target(&self, path: &Path) -> bool {
+        self.target_paths.contains(path) || self.target_files.contains_key(path)
+    }
+
+    /// Let all sweepers inspect the path.
+    /// Note: Sweepers may identify targets other than the path itself,
+    /// e.g. all module build directories when inspecting a project root.
+    pub(crate) fn identify_targets(
+        &mut self,
+        path: &Path,
+        mut current_index: Option<&mut ScanIndex>,
+    ) -> Result<(), CrewAborted> {
+        let mut identified_targets = Vec::new();
+        let mut sweeper_errors = Vec::new();
+        for sweeper in &self.members {
+            match sweeper.identify_targets(path) {
+                Ok(targets) => identified_targets.extend(
+                    targets
+                        .into_iter()
+                        .map(|target| (sweeper.name().to_string(), target)),
+                ),
+                Err(error) => {
+                    log::warn!(
+                        "Sweeper {} failed for {}: {:#}",
+                        sweeper.name(),
+                        path.display(),
+                        error
+                    );
+                    sweeper_errors.push((sweeper.name().to_string(), error));
+                }
+            }
+        }
+
+        for (sweeper, error) in sweeper_errors {
+            self.statistics.errors += 1;
+            self.report(CrewReport::ErrorSweeper {
+                error,
+                path: path.to_owned(),
+                sweeper,
+            });
+        }
+
+        for (sweeper, target) in identified_targets {
+            log::trace!(
+                "Identified target {} at {} by {}",
+                target.name(),
+                target.path().display(),
+                sweeper
+            );
+
+            if let Some(index) = current_index.as_deref_mut() {
+                index.insert_target(TargetRecord {
+                    path: target.path().to_owned(),
+                    sweeper: sweeper.clone(),
+                    name: target.name().to_string(),
+                    inspected_path: path.to_owned(),
+                    size: None,
+                    mtime: None,
+                });
+            }
+
+            self.scan_found_targets
+                .insert((sweeper.clone(), target.path().to_owned()));
+            self.send_target(sweeper, target)?;
+        }
+
+        Ok(())
+    }
+
+    /// Search the directory and all its subdirectories for targets.
+    pub(crate) fn scan(
+        &mut self,
+        root_directory: &Path,
+        previous_index: Option<&ScanIndex>,
+        mut current_index: Option<&mut ScanIndex>,
+    ) -> Result<(), CrewAborted> {
+        self.scan_found_targets.clear();
+        self.scan_entered_dirs.clear();
+
+        let mut dir_walker = DirWalker::new();
+        self.enter_directory(
+            &mut dir_walker,
+            root_directory,
+            previous_index,
+            current_index.as_deref_mut(),
+        );
+
+        while let Some(item) = dir_walker.next_item() {
+            self.check_runnable()?;
+
+            let item_path = item.path().to_owned();
+            self.statistics.entries_inspected += 1;
+            self.report(CrewReport::StatusInspecting(item_path.clone()));
+
+            self.identify_targets(&item_path, current_index.as_deref_mut())?;
+            match item.kind() {
+                EntryKind::Directory => {}
+                EntryKind::Symlink => {
+                    if item_path.is_dir() {
+                        self.report(CrewReport::DirectorySkipped {
+                            path: item_path,
+                            reason: SkipReason::Symlink,
+                        });
                     }
+                    continue;
                 }
+                EntryKind::File | EntryKind::Other => continue,
             }
-        });
 
-        (handle, rx)
+            if self.is_target(&item_path) && !self.options.search_recursively {
+                self.report(CrewReport::DirectorySkipped {
+                    path: item_path,
+                    reason: SkipReason::TargetIdentified,
+                });
+                continue;
+            }
+
+            self.enter_directory(
+                &mut dir_walker,
+                &item_path,
+                previous_index,
+                current_index.as_deref_mut(),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn enter_directory(
+        &mut self,
+        dir_walker: &mut DirWalker,
+        path: &Path,
+        previous_index: Option<&ScanIndex>,
+        current_index: Option<&mut ScanIndex>,
+    ) {
+        match read_directory(path, previous_index, current_index) {
+            Ok(listing) => {
+                self.statistics.dirs_visited += 1;
+                dir_walker.insert_listing(path, &listing);
+                if self.options.watch {
+                    self.scan_entered_dirs.push(path.to_owned());
+                }
+                self.report(CrewReport::DirectoryEntered(path.to_owned()));
+            }
+            Err(error) => {
+                log::warn!("Failed to read directory {}: {:#}", path.display(), error);
+                self.statistics.errors += 1;
+                self.report(CrewReport::ErrorFs {
+                    path: path.to_owned(),
+                    error,
+                });
+            }
+        }
+    }
+}
+
+/// Read the directory listing or use the listing from the previous index
+/// if the directory has not been modified since.
+fn read_directory(
+    path: &Path,
+    previous_index: Option<&ScanIndex>,
+    current_index: Option<&mut ScanIndex>,
+) -> io::Result<DirListing> {
+    let Some(current_index) = current_index else {
+        return read_listing(path);
+    };
+
+    /* the modification time must be queried before reading the directory */
+    let mtime = modified_time(path);
+    let listing = match previous_index.and_then(|index| index.cached_listing(path, mtime)) {
+        Some(listing) => listing,
+        None => read_listing(path)?,
+    };
+
+    current_index.insert_listing(path, mtime, &listing);
+    Ok(listing)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        path::Path,
+        process,
+        sync::{
+            Arc,
+            Mutex,
+        },
+    };
+
+    use super::{
+        CrewOptions,
+        CrewReport,
+        CrewReportConsumer,
+        SweeperCrew,
+    };
+    use crate::{
+        index::ScanIndex,
+        target::{
+            DirectoryTarget,
+            FileSetTarget,
+        },
+        SweepableTarget,
+        Sweeper,
+        SweeperError,
+    };
+
+    /// Identifies every directory named `dist`
+    struct DistSweeper(&'static str);
+
+    impl Sweeper for DistSweeper {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn identify_targets(
+            &self,
+            path: &Path,
+        ) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+            if path.ends_with("dist") {
+                Ok(vec![Box::new(DirectoryTarget::named(
+                    self.0,
+                    path.to_owned(),
+                ))])
+            } else {
+                Ok(vec![])
+            }
+        }
+    }
+
+    #[test]
+    fn sends_each_path_once() {
+        let root = env::temp_dir().join(format!("bclean-crew-{}", process::id()));
+        fs::create_dir_all(root.join("project").join("dist")).unwrap();
+
+        let mut crew = SweeperCrew::new();
+        crew.register(DistSweeper("py-dist"));
+        crew.register(DistSweeper("dist"));
+        let (handle, targets) = crew.execute(root.clone(), CrewOptions::default());
+        let targets = targets.iter().collect::<Vec<_>>();
+        handle.cancel_and_join();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].path(), root.join("project").join("dist"));
+    }
+
+    /// Groups the `dist` directories of all modules of a project
+    struct ProjectSweeper;
+
+    impl Sweeper for ProjectSweeper {
+        fn name(&self) -> &str {
+            "project"
+        }
+
+        fn identify_targets(
+            &self,
+            path: &Path,
+        ) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+            if path.ends_with("project") {
+                Ok(vec![Box::new(FileSetTarget::new(
+                    "project-dist",
+                    path.join("project.toml"),
+                    vec![path.join("a").join("dist"), path.join("b").join("dist")],
+                ))])
+            } else {
+                Ok(vec![])
+            }
+        }
+    }
+
+    #[test]
+    fn skips_files_of_file_sets() {
+        let root = env::temp_dir().join(format!("bclean-crew-files-{}", process::id()));
+        for module in ["a", "b"] {
+            fs::create_dir_all(root.join("project").join(module).join("dist").join("dist"))
+                .unwrap();
+        }
+
+        let mut crew = SweeperCrew::new();
+        crew.register(ProjectSweeper);
+        crew.register(DistSweeper("dist"));
+        let (handle, targets) = crew.execute(root.clone(), CrewOptions::default());
+        let targets = targets.iter().collect::<Vec<_>>();
+        handle.cancel_and_join();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].name(), "project-dist");
+    }
+
+    /// Collects the paths of all targets found and directories entered
+    struct EventCollector(Arc<Mutex<Vec<String>>>);
+
+    impl CrewReportConsumer for EventCollector {
+        fn consume_report(&mut self, report: CrewReport) {
+            let event = match report {
+                CrewReport::TargetFound { path, .. } => format!("target {}", path.display()),
+                CrewReport::DirectoryEntered(path) => format!("entered {}", path.display()),
+                _ => return,
+            };
+            self.0.lock().unwrap().push(event);
+        }
+    }
+
+    #[test]
+    fn revalidates_targets_from_the_inspected_path() {
+        let root = env::temp_dir().join(format!("bclean-crew-index-{}", process::id()));
+        for module in ["a", "b"] {
+            fs::create_dir_all(root.join("project").join(module).join("dist")).unwrap();
+        }
+
+        let scan_index = Arc::new(Mutex::new(ScanIndex::new(root.clone())));
+        let events = Arc::new(Mutex::new(Vec::new()));
+        for _ in 0..2 {
+            let mut crew = SweeperCrew::new();
+            crew.register(ProjectSweeper);
+            let options = CrewOptions {
+                report_consumer: Box::new(EventCollector(events.clone())),
+                scan_index: Some(scan_index.clone()),
+                ..Default::default()
+            };
+            let (handle, targets) = crew.execute(root.clone(), options);
+            targets.iter().for_each(drop);
+            handle.cancel_and_join();
+        }
+        fs::remove_dir_all(&root).unwrap();
+
+        let index = scan_index.lock().unwrap();
+        assert_eq!(index.targets().len(), 1);
+        assert_eq!(index.targets()[0].inspected_path, root.join("project"));
+
+        /* the second run reports the target before entering the root directory */
+        let events = events.lock().unwrap();
+        let second_run = &events[events.len() / 2..];
+        assert_eq!(
+            second_run[0],
+            format!(
+                "target {}",
+                root.join("project").join("project.toml").display()
+            )
+        );
     }
 }
@@ -0,0 +1,56 @@
+use std::{
+    env,
+    path::PathBuf,
+};
+
+pub(crate) fn env_path(name: &str) -> Option<PathBuf> {
+    env::var_os(name)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Returns the home directory of the current user
+pub fn home_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        env_path("USERPROFILE")
+    } else {
+        env_path("HOME")
+    }
+}
+
+/// Returns the users cache directory.
+/// On unix systems this respects `XDG_CACHE_HOME` and defaults to `~/.cache`.
+pub fn cache_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        env_path("LOCALAPPDATA")
+    } else if cfg!(target_os = "macos") {
+        home_dir().map(|home| home.join("Library").join("Caches"))
+    } else {
+        env_path("XDG_CACHE_HOME").or_else(|| home_dir().map(|home| home.join(".cache")))
+    }
+}
+
+/// Returns the users data directory.
+/// On unix systems this respects `XDG_DATA_HOME` and defaults to `~/.local/share`.
+pub fn data_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        env_path("LOCALAPPDATA")
+    } else if cfg!(target_os = "macos") {
+        home_dir().map(|home| home.join("Library"))
+    } else {
+        env_path("XDG_DATA_HOME")
+            .or_else(|| home_dir().map(|home| home.join(".local").join("share")))
+    }
+}
+
+/// Returns the users configuration directory.
+/// On unix systems this respects `XDG_CONFIG_HOME` and defaults to `~/.config`.
+pub fn config_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        env_path("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        home_dir().map(|home| home.join("Library").join("Application Support"))
+    } else {
+        env_path("XDG_CONFIG_HOME").or_else(|| home_dir().map(|home| home.join(".config")))
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    ffi::OsString,
     fs::{
         self,
         DirEntry,
@@ -12,11 +13,68 @@ use std::{
     time::{
         Duration,
         Instant,
+        UNIX_EPOCH,
     },
 };
 
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Type of a directory entry.
+/// Symbolic links are never followed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EntryKind {
+    File,
+    Directory,
+    Symlink,
+    Other,
+}
+
+impl EntryKind {
+    fn from_entry(entry: &DirEntry) -> Self {
+        match entry.file_type() {
+            Ok(file_type) if file_type.is_symlink() => Self::Symlink,
+            Ok(file_type) if file_type.is_dir() => Self::Directory,
+            Ok(file_type) if file_type.is_file() => Self::File,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// The names and types of all entries of a directory
+pub(crate) type DirListing = Vec<(OsString, EntryKind)>;
+
+pub(crate) fn read_listing(path: &Path) -> io::Result<DirListing> {
+    let listing = fs::read_dir(path)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| {
+            let kind = EntryKind::from_entry(&entry);
+            (entry.file_name(), kind)
+        })
+        .collect();
+
+    Ok(listing)
+}
+
+pub(crate) struct WalkEntry {
+    path: PathBuf,
+    kind: EntryKind,
+}
+
+impl WalkEntry {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn kind(&self) -> EntryKind {
+        self.kind
+    }
+}
+
 pub(crate) struct DirWalker {
-    pending_entries: Vec<DirEntry>,
+    pending_entries: Vec<WalkEntry>,
 }
 
 impl DirWalker {
@@ -26,22 +84,127 @@ impl DirWalker {
         }
     }
 
-    pub fn next_item(&mut self) -> Option<DirEntry> {
+    pub fn next_item(&mut self) -> Option<WalkEntry> {
         self.pending_entries.pop()
     }
 
     pub fn insert_entries(&mut self, path: &Path) -> io::Result<()> {
-        for entry in fs::read_dir(path)? {
-            let entry = match entry {
-                Ok(entry) => entry,
-                Err(_err) => continue,
-            };
+        let listing = read_listing(path)?;
+        self.insert_listing(path, &listing);
+        Ok(())
+    }
+
+    pub fn insert_listing(&mut self, path: &Path, listing: &DirListing) {
+        self.pending_entries
+            .extend(listing.iter().map(|(name, kind)| WalkEntry {
+                path: path.join(name),
+                kind: *kind,
+            }));
+    }
+}
+
+/// Returns the last modification time of the file or directory
+/// in nanoseconds since the unix epoch.
+pub(crate) fn modified_time(path: &Path) -> Option<u64> {
+    let modified = fs::symlink_metadata(path).ok()?.modified().ok()?;
+    let modified = modified.duration_since(UNIX_EPOCH).ok()?;
+    u64::try_from(modified.as_nanos()).ok()
+}
+
+/// Summary of a file or directory tree which changes whenever an entry within the tree
+/// has been added, removed or modified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TreeSignature {
+    /// Latest modification time of all entries in nanoseconds since the unix epoch
+    modified: u64,
+    entries: u64,
+    size: u64,
+}
 
-            self.pending_entries.push(entry);
+impl TreeSignature {
+    /// Combine the signatures of multiple trees, e.g. all files of a file set.
+    pub(crate) fn merge(self, other: Self) -> Self {
+        Self {
+            modified: self.modified.max(other.modified),
+            entries: self.entries + other.entries,
+            size: self.size + other.size,
         }
+    }
+}
 
-        Ok(())
+/// Returns the signature of the file or directory itself without inspecting its entries.
+pub(crate) fn entry_signature(path: &Path) -> Option<TreeSignature> {
+    Some(TreeSignature {
+        modified: modified_time(path)?,
+        entries: 1,
+        size: fs::symlink_metadata(path).ok()?.len(),
+    })
+}
+
+/// Returns the signature of the file or directory tree.
+/// Symbolic links are not followed.
+pub(crate) fn tree_signature(path: &Path) -> Option<TreeSignature> {
+    let mut signature = entry_signature(path)?;
+    if !path.is_dir() || path.is_symlink() {
+        return Some(signature);
+    }
+
+    let mut walker = DirWalker::new();
+    let _ = walker.insert_entries(path);
+    while let Some(entry) = walker.next_item() {
+        signature.entries += 1;
+        if let Some(modified) = modified_time(entry.path()) {
+            signature.modified = signature.modified.max(modified);
+        }
+
+        match entry.kind() {
+            EntryKind::File => {
+                if let Ok(metadata) = fs::symlink_metadata(entry.path()) {
+                    signature.size += metadata.len();
+                }
+            }
+            EntryKind::Directory => {
+                let _ = walker.insert_entries(entry.path());
+            }
+            EntryKind::Symlink | EntryKind::Other => {}
+        }
+    }
+
+    Some(signature)
+}
+
+/// Remove the directory and all its contents including read-only entries.
+/// Write permissions will be granted to all directories and files before removing them.
+pub(crate) fn remove_dir_all_writable(dir: &Path) -> io::Result<()> {
+    let mut pending = vec![dir.to_owned()];
+    while let Some(path) = pending.pop() {
+        let metadata = fs::symlink_metadata(&path)?;
+        if metadata.file_type().is_symlink() {
+            continue;
+        }
+
+        let mut permissions = metadata.permissions();
+        if permissions.readonly() {
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                permissions.set_mode(permissions.mode() | 0o700);
+            }
+            #[cfg(not(unix))]
+            #[allow(clippy::permissions_set_readonly_false)]
+            permissions.set_readonly(false);
+
+            fs::set_permissions(&path, permissions)?;
+        }
+
+        if metadata.is_dir() {
+            for entry in fs::read_dir(&path)? {
+                pending.push(entry?.path());
+            }
+        }
     }
+
+    fs::remove_dir_all(dir)
 }
 
 pub fn estimate_size_async(dir: PathBuf) -> impl Iterator<Item = u64> {
@@ -50,16 +213,18 @@ pub fn estimate_size_async(dir: PathBuf) -> impl Iterator<Item = u64> {
 
     let mut size_iter = iter::from_fn(move || {
         while let Some(current_entry) = walker.next_item() {
-            let file_meta = match current_entry.metadata() {
-                Ok(meta) => meta,
-                Err(_) => continue,
-            };
-
-            if file_meta.is_file() {
-                return Some(file_meta.len());
+            match current_entry.kind() {
+                EntryKind::File => {
+                    let Ok(file_meta) = fs::symlink_metadata(current_entry.path()) else {
+                        continue;
+                    };
+                    return Some(file_meta.len());
+                }
+                EntryKind::Directory => {
+                    let _ = walker.insert_entries(current_entry.path());
+                }
+                EntryKind::Symlink | EntryKind::Other => {}
             }
-
-            let _ = walker.insert_entries(&current_entry.path());
         }
         None
     });
@@ -79,3 +244,33 @@ pub fn estimate_size_async(dir: PathBuf) -> impl Iterator<Item = u64> {
         Some(size_total)
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::tree_signature;
+
+    #[test]
+    fn tree_signature_changes_deep_within_the_tree() {
+        let root = env::temp_dir().join(format!("bclean-signature-{}", process::id()));
+        let deps = root.join("debug").join("deps");
+        fs::create_dir_all(&deps).unwrap();
+        fs::write(deps.join("libfoo.rlib"), "foo").unwrap();
+
+        let initial = tree_signature(&root);
+        fs::write(deps.join("libfoo.rlib"), "foobar").unwrap();
+        let rewritten = tree_signature(&root);
+        fs::write(deps.join("libbar.rlib"), "").unwrap();
+        let added = tree_signature(&root);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(initial.is_some());
+        assert_ne!(initial, rewritten);
+        assert_ne!(rewritten, added);
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    collections::HashMap,
+    ffi::OsString,
+    fs::{
+        self,
+        File,
+    },
+    io::{
+        self,
+        BufReader,
+        BufWriter,
+        Write,
+    },
+    mem,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    cache_dir,
+    fs::{
+        modified_time,
+        DirListing,
+    },
+    EntryKind,
+};
+
+const INDEX_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DirectoryRecord {
+    mtime: u64,
+    entries: Vec<(String, EntryKind)>,
+}
+
+/// A target which has been identified by a previous scan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetRecord {
+    pub path: PathBuf,
+    pub sweeper: String,
+    pub name: String,
+
+    /// The path the sweeper inspected when identifying the target.
+    /// Sweepers may identify targets other than the inspected path, e.g. module outputs of a project.
+    #[serde(default)]
+    pub inspected_path: PathBuf,
+
+    /// The size of the target if it has been fully estimated
+    pub size: Option<u64>,
+
+    /// The modification time of the target when it has been identified
+    pub mtime: Option<u64>,
+}
+
+/// Persistent index of a previous scan.
+///
+/// The index remembers all identified targets as well as the listing of each visited directory.
+/// Directories whose modification time did not change since the last scan do not need to be read again.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScanIndex {
+    version: u32,
+    root: PathBuf,
+
+    directories: HashMap<PathBuf, DirectoryRecord>,
+    targets: Vec<TargetRecord>,
+
+    #[serde(skip)]
+    file_path: Option<PathBuf>,
+}
+
+impl ScanIndex {
+    /// Create an empty index which will not be persisted.
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            version: INDEX_VERSION,
+            root,
+            ..Default::default()
+        }
+    }
+
+    /// Location of the index file for the given root directory
+    /// within the users cache directory.
+    pub fn default_path(root: &Path) -> Option<PathBuf> {
+        /* FNV-1a as it's stable across rust versions unlike the DefaultHasher */
+        let root_hash = root
+            .to_string_lossy()
+            .bytes()
+            .fold(0xcbf29ce484222325u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            });
+
+        cache_dir().map(|dir| {
+            dir.join("bclean")
+                .join(format!("index-{:016x}.json", root_hash))
+        })
+    }
+
+    /// Open the index for the root directory from the users cache directory.
+    /// Returns an empty index if no index has been saved yet or the index is outdated.
+    pub fn open(root: PathBuf) -> io::Result<Self> {
+        let Some(file_path) = Self::default_path(&root) else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "failed to determine the cache directory",
+            ));
+        };
+
+        let mut index = match File::open(&file_path) {
+            Ok(file) => match serde_json::from_reader::<_, Self>(BufReader::new(file)) {
+                Ok(index) if index.version == INDEX_VERSION && index.root == root => index,
+                Ok(_) => {
+                    log::debug!("Discarding outdated scan index {}", file_path.display());
+                    Self::new(root)
+                }
+                Err(error) => {
+                    log::warn!(
+                        "Failed to parse scan index {}: {:#}",
+                        file_path.display(),
+                        error
+                    );
+                    Self::new(root)
+                }
+            },
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Self::new(root),
+            Err(error) => return Err(error),
+        };
+
+        index.file_path = Some(file_path);
+        Ok(index)
+    }
+
+    /// Save the index to the location it has been opened from.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(file_path) = &self.file_path else {
+            return Ok(());
+        };
+
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut writer = BufWriter::new(File::create(file_path)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn targets(&self) -> &[TargetRecord] {
+        &self.targets
+    }
+
+    /// Returns the known size of the target if the target itself did not change since it has been sized.
+    /// Changes deep within the target are not detected, the size must only be used as a placeholder.
+    pub fn target_size(&self, path: &Path) -> Option<u64> {
+        let record = self.targets.iter().find(|target| target.path == path)?;
+        if record.mtime.is_none() || record.mtime != modified_time(path) {
+            return None;
+        }
+
+        record.size
+    }
+
+    pub fn update_target_size(&mut self, path: &Path, size: u64) {
+        let mtime = modified_time(path);
+        for record in self.targets.iter_mut().filter(|target| target.path == path) {
+            record.size = Some(size);
+            record.mtime = mtime;
+        }
+    }
+
+    pub(crate) fn insert_target(&mut self, mut record: TargetRecord) {
+        if record.path.to_str().is_none() {
+            /* only valid UTF-8 paths can be persisted */
+            return;
+        }
+
+        if record.mtime.is_none() {
+            record.mtime = modified_time(&record.path);
+        }
+        self.targets.push(record);
+    }
+
+    /// Move the directory listings into a new index which will be used to revalidate the scan.
+    /// The targets are copied as their sizes are still queried while scanning.
+    pub(crate) fn take_listings(&mut self) -> ScanIndex {
+        ScanIndex {
+            version: self.version,
+            root: self.root.clone(),
+            directories: mem::take(&mut self.directories),
+            targets: self.targets.clone(),
+            file_path: None,
+        }
+    }
+
+    /// Returns the listing of a directory if the directory has not been modified since it has been recorded.
+    pub(crate) fn cached_listing(&self, path: &Path, mtime: Option<u64>) -> Option<DirListing> {
+        let record = self.directories.get(path)?;
+        if Some(record.mtime) != mtime {
+            return None;
+        }
+
+        let listing = record
+            .entries
+            .iter()
+            .map(|(name, kind)| (OsString::from(name), *kind))
+            .collect();
+        Some(listing)
+    }
+
+    pub(crate) fn insert_listing(&mut self, path: &Path, mtime: Option<u64>, listing: &DirListing) {
+        let Some(mtime) = mtime else { return };
+        if path.to_str().is_none() {
+            return;
+        }
+
+        let entries = listing
+            .iter()
+            .map(|(name, kind)| name.to_str().map(|name| (name.to_string(), *kind)))
+            .collect::<Option<Vec<_>>>();
+
+        let Some(entries) = entries else {
+            /* directories containing non UTF-8 names will always be read again */
+            return;
+        };
+
+        self.directories
+            .insert(path.to_owned(), DirectoryRecord { mtime, entries });
+    }
+
+    /// Replace the scan results of this index with the results of the new index.
+    /// Sizes of targets which did not change will be kept.
+    pub(crate) fn replace_with(&mut self, mut index: ScanIndex) {
+        for target in index.targets.iter_mut() {
+            if target.size.is_some() {
+                continue;
+            }
+
+            let previous = self
+                .targets
+                .iter()
+                .find(|previous| previous.path == target.path && previous.mtime == target.mtime);
+            if let Some(previous) = previous {
+                target.size = previous.size;
+            }
+        }
+
+        self.directories = index.directories;
+        self.targets = index.targets;
+    }
+}
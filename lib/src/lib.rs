@@ -1,11 +1,16 @@
 mod crew;
+mod dirs;
 mod fs;
+mod index;
 mod path;
 pub mod sweeper;
 pub mod target;
+mod watch;
 
 pub use crew::*;
+pub use dirs::*;
 pub use fs::*;
+pub use index::*;
 pub use path::*;
 pub use sweeper::{
     Sweeper,
@@ -2,7 +2,11 @@ use std::{
     ffi::OsStr,
     fs::DirEntry,
     io,
-    path::Path,
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
 };
 
 static EMPTY_STR: &'static str = "";
@@ -14,6 +18,9 @@ pub trait PathEx {
 
     /// Tests if the path is a directory and contains a certail file but ignoring the name casing
     fn contains_file_ignore_case(&self, file_name: &str) -> io::Result<bool>;
+
+    /// Tests if the directory contains any of the files
+    fn contains_any_file(&self, file_names: &[&str]) -> bool;
 }
 
 impl PathEx for &Path {
@@ -35,6 +42,12 @@ impl PathEx for &Path {
 
         Ok(contains_file)
     }
+
+    fn contains_any_file(&self, file_names: &[&str]) -> bool {
+        file_names
+            .iter()
+            .any(|file_name| self.join(file_name).is_file())
+    }
 }
 
 pub trait DirEntryEx {
@@ -53,3 +66,52 @@ impl DirEntryEx for DirEntry {
             .map_or(false, |file_type| file_type.is_dir())
     }
 }
+
+/// Lexically resolve the `.` and `..` components of a relative path.
+/// Returns `None` for absolute paths and paths leaving the directory they are relative to.
+pub(crate) fn normalize_relative(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => normalized.push(name),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+
+    Some(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{
+        Path,
+        PathBuf,
+    };
+
+    use super::normalize_relative;
+
+    #[test]
+    fn normalizes_relative_paths() {
+        assert_eq!(
+            normalize_relative(Path::new("./libs/../core/./src")),
+            Some(PathBuf::from("core/src"))
+        );
+        assert_eq!(
+            normalize_relative(Path::new("libs/..")),
+            Some(PathBuf::new())
+        );
+    }
+
+    #[test]
+    fn rejects_paths_outside_the_base() {
+        assert_eq!(normalize_relative(Path::new("../shared")), None);
+        assert_eq!(normalize_relative(Path::new("libs/../../shared")), None);
+        assert_eq!(normalize_relative(Path::new("/usr/lib")), None);
+    }
+}
@@ -0,0 +1,267 @@
+use std::{
+    env,
+    fs,
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
+};
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    dirs::{
+        cache_dir,
+        env_path,
+        home_dir,
+    },
+    target::{
+        CacheEntryFilter,
+        TrimCacheTarget,
+    },
+};
+
+/// Maximum size of ccache if it has not been configured
+const CCACHE_DEFAULT_MAX_SIZE: u64 = 5 * 1000 * 1000 * 1000;
+
+/// Maximum size of sccache if it has not been configured
+const SCCACHE_DEFAULT_MAX_SIZE: u64 = 10 * 1024 * 1024 * 1024;
+
+/// Parse a cache size like `5.0G`, `500M` or `10Gi`.
+/// Suffixes ending with `i` are binary units, others use the base of the tool.
+/// Sizes without a suffix use the default suffix of the tool.
+fn parse_cache_size(value: &str, base: u64, default_suffix: &str) -> Option<u64> {
+    let value = value.trim();
+    let number_length = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, suffix) = value.split_at(number_length);
+    let suffix = match suffix.trim() {
+        "" => default_suffix,
+        suffix => suffix,
+    };
+
+    let (suffix, base) = match suffix.strip_suffix('i') {
+        Some(suffix) => (suffix, 1024),
+        None => (suffix, base),
+    };
+    let exponent = match suffix.to_ascii_uppercase().as_str() {
+        "" | "B" => 0,
+        "K" => 1,
+        "M" => 2,
+        "G" => 3,
+        "T" => 4,
+        _ => return None,
+    };
+
+    let size = number.parse::<f64>().ok()? * base.pow(exponent) as f64;
+    (size.is_finite() && size >= 0.0).then_some(size as u64)
+}
+
+/// Returns the maximum size configured for the cache within the directory,
+/// or `None` if the size of the cache is unlimited.
+type CacheMaxSize = fn(&Path) -> Option<u64>;
+
+/// Returns the `max_size` of the ccache configuration like `ccache --get-config max_size` would.
+/// Returns `None` if the size of the cache is unlimited.
+fn ccache_max_size(cache_dir: &Path) -> Option<u64> {
+    let configured = env::var("CCACHE_MAXSIZE").ok().or_else(|| {
+        let config = fs::read_to_string(cache_dir.join("ccache.conf")).ok()?;
+        config.lines().find_map(|line| {
+            let (key, value) = line.split('#').next()?.split_once('=')?;
+            (key.trim() == "max_size").then(|| value.trim().to_string())
+        })
+    });
+
+    let max_size = configured
+        .and_then(|value| parse_cache_size(&value, 1000, "G"))
+        .unwrap_or(CCACHE_DEFAULT_MAX_SIZE);
+    /* a size of zero disables the limit */
+    (max_size > 0).then_some(max_size)
+}
+
+/// Returns the maximum size of sccache configured by `SCCACHE_CACHE_SIZE`.
+fn sccache_max_size(_cache_dir: &Path) -> Option<u64> {
+    let max_size = env::var("SCCACHE_CACHE_SIZE")
+        .ok()
+        .and_then(|value| parse_cache_size(&value, 1024, "B"))
+        .unwrap_or(SCCACHE_DEFAULT_MAX_SIZE);
+    Some(max_size)
+}
+
+/// Returns the depth of the path and its first component.
+fn path_layout(path: &Path) -> (usize, Option<&str>) {
+    let mut components = path.components();
+    let first = components.next().and_then(|component| match component {
+        Component::Normal(name) => name.to_str(),
+        _ => None,
+    });
+    (components.count() + 1, first)
+}
+
+/// ccache stores its entries within two levels of hex directories like `a/b/<hash>R`.
+/// The statistics, configuration, locks and temporary files must be kept.
+fn is_ccache_entry(path: &Path) -> bool {
+    let (depth, first) = path_layout(path);
+    if depth < 2 || matches!(first, Some("tmp" | "lock")) {
+        return false;
+    }
+
+    let file_name = path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy())
+        .unwrap_or_default();
+    file_name != "stats" && !file_name.ends_with(".lock") && !file_name.contains(".tmp")
+}
+
+/// sccache stores its entries within two levels of directories like `a/b/<hash>`.
+fn is_sccache_entry(path: &Path) -> bool {
+    let (depth, _) = path_layout(path);
+    depth == 3
+}
+
+fn ccache_dir() -> Option<PathBuf> {
+    if let Some(path) = env_path("CCACHE_DIR") {
+        return Some(path);
+    }
+
+    /* ccache prefers the legacy location if it exists */
+    let legacy_dir = home_dir().map(|home| home.join(".ccache"));
+    match legacy_dir {
+        Some(legacy_dir) if legacy_dir.is_dir() => Some(legacy_dir),
+        _ => cache_dir().map(|cache_dir| cache_dir.join("ccache")),
+    }
+}
+
+fn sccache_dir() -> Option<PathBuf> {
+    if let Some(path) = env_path("SCCACHE_DIR") {
+        return Some(path);
+    }
+
+    cache_dir().map(|cache_dir| {
+        if cfg!(windows) {
+            cache_dir.join("Mozilla").join("sccache").join("cache")
+        } else if cfg!(target_os = "macos") {
+            cache_dir.join("Mozilla.sccache")
+        } else {
+            cache_dir.join("sccache")
+        }
+    })
+}
+
+/// Trims the local caches of ccache and sccache to a size limit.
+/// Note: ccache recalculates its size counters when cleaning up a directory
+/// which has been trimmed, the statistics may be outdated until then.
+#[derive(Default)]
+pub struct CompilerCacheSweeper {
+    /// Size the caches will be trimmed to, defaults to the maximum size configured for each cache
+    size_limit: Option<u64>,
+}
+
+impl CompilerCacheSweeper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_size_limit(size_limit: u64) -> Self {
+        Self {
+            size_limit: Some(size_limit),
+        }
+    }
+}
+
+impl Sweeper for CompilerCacheSweeper {
+    fn name(&self) -> &str {
+        "compiler-cache"
+    }
+
+    fn identify_targets(
+        &self,
+        _path: &Path,
+    ) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        Ok(vec![])
+    }
+
+    fn identify_global_targets(&self) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        let caches: [(&str, Option<PathBuf>, CacheMaxSize, CacheEntryFilter); 2] = [
+            ("ccache", ccache_dir(), ccache_max_size, is_ccache_entry),
+            ("sccache", sccache_dir(), sccache_max_size, is_sccache_entry),
+        ];
+
+        let targets = caches
+            .into_iter()
+            .filter_map(|(name, path, max_size, is_cache_entry)| {
+                let path = path.filter(|path| path.is_dir())?;
+                /* caches without a size limit are not trimmed by default */
+                let size_limit = self.size_limit.or_else(|| max_size(&path))?;
+                Some(
+                    Box::new(TrimCacheTarget::new(name, path, size_limit, is_cache_entry))
+                        as Box<dyn SweepableTarget>,
+                )
+            })
+            .collect();
+
+        Ok(targets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::{
+        ccache_max_size,
+        parse_cache_size,
+        CCACHE_DEFAULT_MAX_SIZE,
+    };
+
+    #[test]
+    fn parses_cache_sizes() {
+        assert_eq!(parse_cache_size("5.0G", 1000, "G"), Some(5_000_000_000));
+        assert_eq!(parse_cache_size("500M", 1000, "G"), Some(500_000_000));
+        assert_eq!(
+            parse_cache_size("2Gi", 1000, "G"),
+            Some(2 * 1024 * 1024 * 1024)
+        );
+        assert_eq!(parse_cache_size("3", 1000, "G"), Some(3_000_000_000));
+        assert_eq!(
+            parse_cache_size("10G", 1024, "B"),
+            Some(10 * 1024 * 1024 * 1024)
+        );
+        assert_eq!(parse_cache_size("1024", 1024, "B"), Some(1024));
+
+        assert_eq!(parse_cache_size("", 1000, "G"), None);
+        assert_eq!(parse_cache_size("5X", 1000, "G"), None);
+    }
+
+    #[test]
+    fn reads_the_ccache_max_size() {
+        let cache_dir = env::temp_dir().join(format!("bclean-ccache-{}", process::id()));
+        fs::create_dir_all(&cache_dir).unwrap();
+
+        let default = ccache_max_size(&cache_dir);
+        fs::write(
+            cache_dir.join("ccache.conf"),
+            "# max_size = 1G\ncompression = true\nmax_size = 20G # shared\n",
+        )
+        .unwrap();
+        let configured = ccache_max_size(&cache_dir);
+        fs::write(cache_dir.join("ccache.conf"), "max_size = 0\n").unwrap();
+        let unlimited = ccache_max_size(&cache_dir);
+        fs::remove_dir_all(&cache_dir).unwrap();
+
+        if env::var_os("CCACHE_MAXSIZE").is_none() {
+            assert_eq!(default, Some(CCACHE_DEFAULT_MAX_SIZE));
+            assert_eq!(configured, Some(20_000_000_000));
+            assert_eq!(unlimited, None);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    fs,
+    io::{
+        self,
+        Read,
+    },
+    path::Path,
+};
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::PathEx,
+    target::FileSetTarget,
+};
+
+const ELF_MAGIC: [u8; 4] = [0x7f, b'E', b'L', b'F'];
+const ELF_DATA_LITTLE_ENDIAN: u8 = 1;
+const ELF_DATA_BIG_ENDIAN: u8 = 2;
+const ELF_TYPE_CORE: u16 = 4;
+
+/// Signature of a windows minidump
+const MINIDUMP_MAGIC: [u8; 4] = *b"MDMP";
+
+fn read_header<const N: usize>(path: &Path) -> Result<Option<[u8; N]>, SweeperError> {
+    let mut header = [0u8; N];
+    let mut file = fs::File::open(path)?;
+    match file.read_exact(&mut header) {
+        Ok(()) => Ok(Some(header)),
+        Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Length of the ELF header up to and including `e_type`
+const ELF_TYPE_END: usize = 18;
+
+/// Test if the header is the header of an ELF core file by checking `e_type == ET_CORE`.
+fn is_elf_core_header(header: &[u8; ELF_TYPE_END]) -> bool {
+    if header[..4] != ELF_MAGIC {
+        return false;
+    }
+
+    let elf_type = match header[5] {
+        ELF_DATA_LITTLE_ENDIAN => u16::from_le_bytes([header[16], header[17]]),
+        ELF_DATA_BIG_ENDIAN => u16::from_be_bytes([header[16], header[17]]),
+        _ => return false,
+    };
+    elf_type == ELF_TYPE_CORE
+}
+
+fn is_elf_core(path: &Path) -> Result<bool, SweeperError> {
+    Ok(read_header::<ELF_TYPE_END>(path)?.is_some_and(|header| is_elf_core_header(&header)))
+}
+
+fn is_minidump(path: &Path) -> Result<bool, SweeperError> {
+    Ok(read_header::<4>(path)? == Some(MINIDUMP_MAGIC))
+}
+
+/// Matches `core` and `core.<pid>`
+fn is_core_file_name(file_name: &str) -> bool {
+    match file_name.strip_prefix("core") {
+        Some("") => true,
+        Some(suffix) => suffix
+            .strip_prefix('.')
+            .is_some_and(|pid| !pid.is_empty() && pid.chars().all(|c| c.is_ascii_digit())),
+        None => false,
+    }
+}
+
+/// Matches `hs_err_pid<pid>.log` written by a crashing JVM
+fn is_jvm_crash_log(file_name: &str) -> bool {
+    file_name
+        .strip_prefix("hs_err_pid")
+        .and_then(|file_name| file_name.strip_suffix(".log"))
+        .is_some_and(|pid| !pid.is_empty() && pid.chars().all(|c| c.is_ascii_digit()))
+}
+
+#[derive(Default)]
+pub struct CoreDumpSweeper;
+
+impl CoreDumpSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Sweeper for CoreDumpSweeper {
+    fn name(&self) -> &str {
+        "core-dump"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        let file_name = path.file_name_truncate();
+        let target_name = if is_core_file_name(file_name) {
+            "core-dump"
+        } else if file_name.starts_with("vgcore.") {
+            "valgrind-core"
+        } else if file_name.ends_with(".dmp") {
+            "minidump"
+        } else if is_jvm_crash_log(file_name) {
+            "jvm-crash-log"
+        } else {
+            return Ok(vec![]);
+        };
+
+        /* never follow symlinks as the link target may be located outside of the scanned root */
+        if !fs::symlink_metadata(path)?.is_file() {
+            return Ok(vec![]);
+        }
+
+        let verified = match target_name {
+            /* a source file named core must never be matched */
+            "core-dump" | "valgrind-core" => is_elf_core(path)?,
+            "minidump" => is_minidump(path)?,
+            _ => true,
+        };
+        if !verified {
+            return Ok(vec![]);
+        }
+
+        Ok(vec![Box::new(FileSetTarget::new(
+            target_name,
+            path.to_owned(),
+            vec![path.to_owned()],
+        ))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::{
+        is_core_file_name,
+        is_elf_core_header,
+        is_jvm_crash_log,
+        CoreDumpSweeper,
+        ELF_TYPE_END,
+    };
+    use crate::Sweeper;
+
+    fn elf_header(data: u8, elf_type: [u8; 2]) -> [u8; ELF_TYPE_END] {
+        let mut header = [0; ELF_TYPE_END];
+        header[..4].copy_from_slice(b"\x7fELF");
+        header[4] = 2;
+        header[5] = data;
+        header[16..].copy_from_slice(&elf_type);
+        header
+    }
+
+    #[test]
+    fn detects_elf_core_headers() {
+        assert!(is_elf_core_header(&elf_header(1, [4, 0])));
+        assert!(is_elf_core_header(&elf_header(2, [0, 4])));
+
+        /* executables, shared objects and unknown byte orders */
+        assert!(!is_elf_core_header(&elf_header(1, [2, 0])));
+        assert!(!is_elf_core_header(&elf_header(1, [3, 0])));
+        assert!(!is_elf_core_header(&elf_header(2, [4, 0])));
+        assert!(!is_elf_core_header(&elf_header(0, [4, 0])));
+
+        let mut header = elf_header(1, [4, 0]);
+        header[0] = b'E';
+        assert!(!is_elf_core_header(&header));
+    }
+
+    #[test]
+    fn matches_file_names() {
+        assert!(is_core_file_name("core"));
+        assert!(is_core_file_name("core.1234"));
+        assert!(!is_core_file_name("core."));
+        assert!(!is_core_file_name("core.rs"));
+        assert!(!is_core_file_name("corefile"));
+
+        assert!(is_jvm_crash_log("hs_err_pid4711.log"));
+        assert!(!is_jvm_crash_log("hs_err_pid.log"));
+        assert!(!is_jvm_crash_log("hs_err_pidX.log"));
+    }
+
+    #[test]
+    fn verifies_file_contents() {
+        let root = env::temp_dir().join(format!("bclean-core-dump-{}", process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("core.42"), elf_header(1, [4, 0])).unwrap();
+        fs::write(root.join("src").join("core"), "pub mod core;").unwrap();
+        fs::write(root.join("crash.dmp"), b"MDMP\x93\xa7").unwrap();
+        fs::write(root.join("disk.dmp"), b"MD").unwrap();
+
+        let sweeper = CoreDumpSweeper::new();
+        let identified = ["core.42", "src/core", "crash.dmp", "disk.dmp"].map(|file| {
+            !sweeper
+                .identify_targets(&root.join(file))
+                .unwrap()
+                .is_empty()
+        });
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(identified, [true, false, true, false]);
+    }
+}
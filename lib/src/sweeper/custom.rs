@@ -0,0 +1,342 @@
+use std::{
+    fs,
+    io::Read,
+    path::Path,
+};
+
+use regex::Regex;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use thiserror::Error;
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    fs::{
+        read_listing,
+        EntryKind,
+    },
+    path::PathEx,
+    target::DirectoryTarget,
+};
+
+/// Number of bytes of a marker file which will be matched against its content pattern
+const MARKER_CONTENT_LENGTH: u64 = 1024 * 1024;
+
+/// Location of a marker file relative to the matched directory
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MarkerLocation {
+    /// The marker is located next to the directory
+    #[default]
+    Sibling,
+    /// The marker is located within the directory
+    Child,
+}
+
+/// A file or directory which identifies the kind of a directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MarkerDefinition {
+    /// Glob matched against the name of the marker, supports `*` and `?`
+    pub name: String,
+
+    #[serde(default)]
+    pub location: MarkerLocation,
+
+    /// Regular expression which must match the content of the marker file
+    #[serde(default)]
+    pub content: Option<String>,
+}
+
+/// Definition of a sweeper which has been declared by the user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CustomSweeperDefinition {
+    /// Name used to select the sweeper
+    pub name: String,
+
+    /// Name of the targets shown to the user, defaults to the sweeper name
+    #[serde(default)]
+    pub display_name: Option<String>,
+
+    /// Glob matched against the directory name, supports `*` and `?`
+    pub directory: String,
+
+    /// Markers which all must be present
+    #[serde(default)]
+    pub required: Vec<MarkerDefinition>,
+
+    /// Markers which must not be present
+    #[serde(default)]
+    pub forbidden: Vec<MarkerDefinition>,
+}
+
+#[derive(Error, Debug)]
+pub enum CustomSweeperError {
+    #[error("sweeper {sweeper}: invalid pattern {pattern}: {source}")]
+    InvalidPattern {
+        sweeper: String,
+        pattern: String,
+        source: regex::Error,
+    },
+    #[error("sweeper {sweeper}: at least one required marker has to be declared")]
+    MissingRequiredMarker { sweeper: String },
+}
+
+/// Converts a glob into an anchored regular expression.
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::with_capacity(glob.len() + 2);
+    regex.push('^');
+    for char in glob.chars() {
+        match char {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            char => regex.push_str(&regex::escape(char.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+struct Marker {
+    name: Regex,
+    location: MarkerLocation,
+    content: Option<Regex>,
+}
+
+impl Marker {
+    /// Test if the directory containing the marker has any entry matching the marker.
+    fn is_present(&self, dir: &Path) -> Result<bool, SweeperError> {
+        for (name, kind) in read_listing(dir)? {
+            if !self.name.is_match(&name.to_string_lossy()) {
+                continue;
+            }
+
+            let Some(content) = &self.content else {
+                return Ok(true);
+            };
+
+            if kind == EntryKind::File && Self::content_matches(&dir.join(name), content)? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn content_matches(path: &Path, pattern: &Regex) -> Result<bool, SweeperError> {
+        let mut content = Vec::new();
+        fs::File::open(path)?
+            .take(MARKER_CONTENT_LENGTH)
+            .read_to_end(&mut content)?;
+
+        Ok(pattern.is_match(&String::from_utf8_lossy(&content)))
+    }
+}
+
+/// A sweeper identifying directories as declared by a [`CustomSweeperDefinition`].
+pub struct CustomSweeper {
+    name: String,
+    display_name: String,
+    directory: Regex,
+    required: Vec<Marker>,
+    forbidden: Vec<Marker>,
+}
+
+impl CustomSweeper {
+    pub fn new(definition: CustomSweeperDefinition) -> Result<Self, CustomSweeperError> {
+        /* a directory name alone would match unrelated directories */
+        if definition.required.is_empty() {
+            return Err(CustomSweeperError::MissingRequiredMarker {
+                sweeper: definition.name,
+            });
+        }
+
+        let compile = |pattern: String| {
+            Regex::new(&pattern).map_err(|source| CustomSweeperError::InvalidPattern {
+                sweeper: definition.name.clone(),
+                pattern,
+                source,
+            })
+        };
+        let compile_markers = |markers: &[MarkerDefinition]| {
+            markers
+                .iter()
+                .map(|marker| {
+                    Ok(Marker {
+                        name: compile(glob_to_regex(&marker.name))?,
+                        location: marker.location,
+                        content: marker.content.clone().map(compile).transpose()?,
+                    })
+                })
+                .collect::<Result<Vec<_>, CustomSweeperError>>()
+        };
+
+        Ok(Self {
+            directory: compile(glob_to_regex(&definition.directory))?,
+            required: compile_markers(&definition.required)?,
+            forbidden: compile_markers(&definition.forbidden)?,
+            display_name: definition
+                .display_name
+                .clone()
+                .unwrap_or_else(|| definition.name.clone()),
+            name: definition.name,
+        })
+    }
+}
+
+impl Sweeper for CustomSweeper {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !path.is_dir()
+            || path.is_symlink()
+            || !self.directory.is_match(path.file_name_truncate())
+        {
+            return Ok(vec![]);
+        }
+
+        let Some(parent) = path.parent() else {
+            return Ok(vec![]);
+        };
+        let marker_dir = |marker: &Marker| match marker.location {
+            MarkerLocation::Sibling => parent,
+            MarkerLocation::Child => path,
+        };
+
+        for marker in &self.required {
+            if !marker.is_present(marker_dir(marker))? {
+                return Ok(vec![]);
+            }
+        }
+        for marker in &self.forbidden {
+            if marker.is_present(marker_dir(marker))? {
+                return Ok(vec![]);
+            }
+        }
+
+        Ok(vec![Box::new(DirectoryTarget::named(
+            self.display_name.clone(),
+            path.to_owned(),
+        ))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use regex::Regex;
+
+    use super::{
+        glob_to_regex,
+        CustomSweeper,
+        CustomSweeperDefinition,
+    };
+    use crate::Sweeper;
+
+    fn glob_matches(glob: &str, name: &str) -> bool {
+        Regex::new(&glob_to_regex(glob)).unwrap().is_match(name)
+    }
+
+    #[test]
+    fn converts_globs() {
+        assert!(glob_matches("out-*", "out-debug"));
+        assert!(glob_matches("out-*", "out-"));
+        assert!(glob_matches("*.acme", "project.acme"));
+        assert!(glob_matches("build-?", "build-1"));
+        assert!(glob_matches("exact", "exact"));
+
+        assert!(!glob_matches("out-*", "my-out-debug"));
+        assert!(!glob_matches("build-?", "build-10"));
+        assert!(!glob_matches("exact", "exactly"));
+    }
+
+    #[test]
+    fn escapes_regex_characters() {
+        assert!(glob_matches("a.b", "a.b"));
+        assert!(!glob_matches("a.b", "axb"));
+        assert!(glob_matches("(out)[1]+", "(out)[1]+"));
+        assert!(glob_matches("^$", "^$"));
+    }
+
+    #[test]
+    fn rejects_invalid_content_patterns() {
+        let definition: CustomSweeperDefinition = serde_json::from_str(
+            r#"{"name": "broken", "directory": "out", "required": [{"name": "x", "content": "("}]}"#,
+        )
+        .unwrap();
+        assert!(CustomSweeper::new(definition).is_err());
+    }
+
+    #[test]
+    fn requires_a_required_marker() {
+        let definition: CustomSweeperDefinition = serde_json::from_str(
+            r#"{"name": "unbound", "directory": "out", "forbidden": [{"name": "KEEP"}]}"#,
+        )
+        .unwrap();
+        assert!(CustomSweeper::new(definition).is_err());
+    }
+
+    #[test]
+    fn identifies_declared_directories() {
+        let definition: CustomSweeperDefinition = serde_json::from_str(
+            r#"{
+                "name": "acme",
+                "display-name": "acme-out",
+                "directory": "out-*",
+                "required": [
+                    {"name": "*.acme", "content": "tool\\s*=\\s*acme"},
+                    {"name": "obj", "location": "child"}
+                ],
+                "forbidden": [{"name": "KEEP"}]
+            }"#,
+        )
+        .unwrap();
+        let sweeper = CustomSweeper::new(definition).unwrap();
+
+        let root = env::temp_dir().join(format!("bclean-custom-{}", process::id()));
+        for (project, marker, keep) in [
+            ("matching", "tool = acme", false),
+            ("forbidden", "tool = acme", true),
+            ("other-tool", "tool = make", false),
+        ] {
+            let project = root.join(project);
+            fs::create_dir_all(project.join("out-debug").join("obj")).unwrap();
+            fs::create_dir_all(project.join("output").join("obj")).unwrap();
+            fs::write(project.join("project.acme"), marker).unwrap();
+            if keep {
+                fs::write(project.join("KEEP"), "").unwrap();
+            }
+        }
+        fs::create_dir_all(root.join("no-child").join("out-debug")).unwrap();
+        fs::write(root.join("no-child").join("project.acme"), "tool=acme").unwrap();
+
+        let identify = |path: &str| sweeper.identify_targets(&root.join(path)).unwrap();
+        let matching = identify("matching/out-debug");
+        let results = [
+            "matching/output",
+            "forbidden/out-debug",
+            "other-tool/out-debug",
+            "no-child/out-debug",
+        ]
+        .map(|path| identify(path).is_empty());
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].name(), "acme-out");
+        assert_eq!(results, [true; 4]);
+    }
+}
@@ -0,0 +1,30 @@
+use std::path::Path;
+
+use super::{
+    marker::identify_marked_dir,
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+
+/// Output directories of dart and flutter next to the `pubspec.yaml`
+const TARGET_DIRS: [(&str, &str); 2] = [(".dart_tool", "dart-tool"), ("build", "dart-build")];
+
+#[derive(Default)]
+pub struct DartSweeper;
+
+impl DartSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Sweeper for DartSweeper {
+    fn name(&self) -> &str {
+        "dart"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        identify_marked_dir(path, "pubspec.yaml", &TARGET_DIRS)
+    }
+}
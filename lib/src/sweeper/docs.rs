@@ -0,0 +1,228 @@
+use std::path::Path;
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::PathEx,
+    target::DirectoryTarget,
+};
+
+const HUGO_CONFIG_FILES: [&str; 3] = ["hugo.toml", "hugo.yaml", "hugo.json"];
+const HUGO_LEGACY_CONFIG_FILES: [&str; 3] = ["config.toml", "config.yaml", "config.json"];
+/// Directories of a hugo site which distinguish the legacy config files from other tools
+const HUGO_SITE_DIRS: [&str; 3] = ["archetypes", "layouts", "themes"];
+
+const DOCUSAURUS_CONFIG_FILES: [&str; 4] = [
+    "docusaurus.config.js",
+    "docusaurus.config.ts",
+    "docusaurus.config.mjs",
+    "docusaurus.config.cjs",
+];
+
+fn is_sphinx_project(path: &Path) -> bool {
+    path.join("conf.py").is_file()
+}
+
+fn is_mkdocs_project(path: &Path) -> bool {
+    path.contains_any_file(&["mkdocs.yml", "mkdocs.yaml"])
+}
+
+fn is_jekyll_site(path: &Path) -> bool {
+    path.contains_any_file(&["_config.yml", "_config.yaml"])
+}
+
+fn is_hugo_site(path: &Path) -> bool {
+    if path.contains_any_file(&HUGO_CONFIG_FILES) {
+        return true;
+    }
+
+    path.contains_any_file(&HUGO_LEGACY_CONFIG_FILES)
+        && HUGO_SITE_DIRS.iter().any(|dir| path.join(dir).is_dir())
+}
+
+fn is_docusaurus_site(path: &Path) -> bool {
+    path.contains_any_file(&DOCUSAURUS_CONFIG_FILES)
+}
+
+/// Output directory of a documentation generator
+struct DocsOutput {
+    /// Path of the directory relative to the project directory
+    path: &'static str,
+    target_name: &'static str,
+    is_project: fn(&Path) -> bool,
+}
+
+const DOCS_OUTPUTS: [DocsOutput; 8] = [
+    DocsOutput {
+        path: "_build",
+        target_name: "sphinx-build",
+        is_project: is_sphinx_project,
+    },
+    DocsOutput {
+        path: "site",
+        target_name: "mkdocs-site",
+        is_project: is_mkdocs_project,
+    },
+    DocsOutput {
+        path: "_site",
+        target_name: "jekyll-site",
+        is_project: is_jekyll_site,
+    },
+    DocsOutput {
+        path: ".jekyll-cache",
+        target_name: "jekyll-cache",
+        is_project: is_jekyll_site,
+    },
+    DocsOutput {
+        path: "public",
+        target_name: "hugo-public",
+        is_project: is_hugo_site,
+    },
+    DocsOutput {
+        path: "resources/_gen",
+        target_name: "hugo-resources",
+        is_project: is_hugo_site,
+    },
+    DocsOutput {
+        path: "build",
+        target_name: "docusaurus-build",
+        is_project: is_docusaurus_site,
+    },
+    DocsOutput {
+        path: ".docusaurus",
+        target_name: "docusaurus-cache",
+        is_project: is_docusaurus_site,
+    },
+];
+
+#[derive(Default)]
+pub struct DocsSweeper;
+
+impl DocsSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Sweeper for DocsSweeper {
+    fn name(&self) -> &str {
+        "docs"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let file_name = path.file_name_truncate();
+        for output in &DOCS_OUTPUTS {
+            if output.path.rsplit('/').next() != Some(file_name) {
+                continue;
+            }
+
+            /* resolve the project directory for nested directories like resources/_gen */
+            let depth = output.path.split('/').count();
+            let Some(project_dir) = path.ancestors().nth(depth) else {
+                continue;
+            };
+            if project_dir.join(output.path) != path || !(output.is_project)(project_dir) {
+                continue;
+            }
+
+            return Ok(vec![Box::new(DirectoryTarget::named(
+                output.target_name,
+                path.to_owned(),
+            ))]);
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::DocsSweeper;
+    use crate::Sweeper;
+
+    #[test]
+    fn identifies_generator_outputs() {
+        let root = env::temp_dir().join(format!("bclean-docs-{}", process::id()));
+        for (project, config_file, output_dir) in [
+            ("sphinx", "conf.py", "_build"),
+            ("mkdocs", "mkdocs.yml", "site"),
+            ("jekyll", "_config.yml", "_site"),
+            ("hugo", "hugo.toml", "resources/_gen"),
+            ("docusaurus", "docusaurus.config.ts", "build"),
+        ] {
+            let project = root.join(project);
+            fs::create_dir_all(project.join(output_dir)).unwrap();
+            fs::write(project.join(config_file), "").unwrap();
+        }
+
+        /* the legacy hugo config requires the hugo site layout */
+        let hugo_legacy = root.join("hugo-legacy");
+        fs::create_dir_all(hugo_legacy.join("public")).unwrap();
+        fs::create_dir_all(hugo_legacy.join("layouts")).unwrap();
+        fs::write(hugo_legacy.join("config.toml"), "").unwrap();
+        let other_tool = root.join("other-tool");
+        fs::create_dir_all(other_tool.join("public")).unwrap();
+        fs::write(other_tool.join("config.toml"), "").unwrap();
+
+        let plain = root.join("plain");
+        for dir in ["_build", "site", "_site", "resources/_gen", "build"] {
+            fs::create_dir_all(plain.join(dir)).unwrap();
+        }
+
+        let sweeper = DocsSweeper::new();
+        let identify = |path: &str| {
+            sweeper
+                .identify_targets(&root.join(path))
+                .unwrap()
+                .iter()
+                .map(|target| target.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        let targets = [
+            "sphinx/_build",
+            "mkdocs/site",
+            "jekyll/_site",
+            "hugo/resources/_gen",
+            "docusaurus/build",
+            "hugo-legacy/public",
+        ]
+        .map(identify);
+        let ignored = [
+            "other-tool/public",
+            "hugo/resources",
+            "plain/_build",
+            "plain/site",
+            "plain/_site",
+            "plain/resources/_gen",
+            "plain/build",
+        ]
+        .map(identify);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            targets,
+            [
+                ["sphinx-build"],
+                ["mkdocs-site"],
+                ["jekyll-site"],
+                ["hugo-resources"],
+                ["docusaurus-build"],
+                ["hugo-public"],
+            ]
+        );
+        assert!(ignored.iter().all(Vec::is_empty));
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::{
+        normalize_relative,
+        PathEx,
+    },
+    target::DirectoryTarget,
+};
+
+const PROJECT_EXTENSIONS: [&str; 3] = ["csproj", "fsproj", "vbproj"];
+
+const DEFAULT_OUTPUT_PATH: &str = "bin";
+const DEFAULT_INTERMEDIATE_OUTPUT_PATH: &str = "obj";
+
+/// Extract the value of the first property with the given name from a MSBuild project file.
+/// Empty elements like `<Name />` have an empty value.
+fn parse_property<'a>(project: &'a str, name: &str) -> Option<&'a str> {
+    let open_tag = format!("<{}", name);
+    let close_tag = format!("</{}>", name);
+
+    let mut input = project;
+    while let Some(start) = input.find(&open_tag) {
+        input = &input[start + open_tag.len()..];
+
+        /* ensure we matched the whole tag name and skip attributes like conditions */
+        if !input.starts_with(|c: char| c == '>' || c == '/' || c.is_whitespace()) {
+            continue;
+        }
+
+        let value_start = input.find('>')? + 1;
+        if input[..value_start - 1].ends_with('/') {
+            return Some("");
+        }
+
+        let value_end = input.find(&close_tag)?;
+        if value_end < value_start {
+            continue;
+        }
+
+        return Some(input[value_start..value_end].trim());
+    }
+
+    None
+}
+
+fn is_project_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            PROJECT_EXTENSIONS
+                .iter()
+                .any(|project_extension| extension.eq_ignore_ascii_case(project_extension))
+        })
+}
+
+/// Output directories of a .NET project
+struct DotNetProject {
+    /// `None` if the directory is located outside of the project directory
+    output_path: Option<PathBuf>,
+    intermediate_output_path: PathBuf,
+}
+
+impl DotNetProject {
+    fn load(project_file: &Path) -> Result<Option<Self>, SweeperError> {
+        let Some(project_dir) = project_file.parent() else {
+            return Ok(None);
+        };
+
+        let project = fs::read_to_string(project_file)?;
+        let output_path =
+            Self::resolve_path(project_dir, &project, "BaseOutputPath", DEFAULT_OUTPUT_PATH);
+        let intermediate_output_path = Self::resolve_path(
+            project_dir,
+            &project,
+            "BaseIntermediateOutputPath",
+            DEFAULT_INTERMEDIATE_OUTPUT_PATH,
+        );
+
+        let Some(intermediate_output_path) = intermediate_output_path else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            output_path,
+            intermediate_output_path,
+        }))
+    }
+
+    /// Resolve the path property relative to the project directory.
+    /// Returns `None` if the path can not be resolved, e.g. because it uses MSBuild properties,
+    /// or if the path is not located within the project directory.
+    fn resolve_path(
+        project_dir: &Path,
+        project: &str,
+        property: &str,
+        default: &str,
+    ) -> Option<PathBuf> {
+        /* an empty property is the same as an unset property */
+        let value = parse_property(project, property)
+            .filter(|value| !value.is_empty())
+            .unwrap_or(default);
+        if value.contains("$(") {
+            return None;
+        }
+
+        let value = value.replace('\\', "/");
+        let value = normalize_relative(Path::new(&value))?;
+        if value.as_os_str().is_empty() {
+            /* the project directory itself */
+            return None;
+        }
+
+        Some(project_dir.join(value))
+    }
+
+    fn identify_targets(&self) -> Vec<Box<dyn SweepableTarget>> {
+        /* the restore writes project.assets.json which proves that the directories are build outputs */
+        if !self
+            .intermediate_output_path
+            .join("project.assets.json")
+            .is_file()
+        {
+            return vec![];
+        }
+
+        let mut targets: Vec<Box<dyn SweepableTarget>> = vec![Box::new(DirectoryTarget::named(
+            "dotnet-obj",
+            self.intermediate_output_path.clone(),
+        ))];
+
+        if let Some(output_path) = &self.output_path {
+            if output_path.is_dir() && *output_path != self.intermediate_output_path {
+                targets.push(Box::new(DirectoryTarget::named(
+                    "dotnet-bin",
+                    output_path.clone(),
+                )));
+            }
+        }
+
+        targets
+    }
+}
+
+#[derive(Default)]
+pub struct DotNetSweeper;
+
+impl DotNetSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Identify the targets of all project files within the directory
+    fn identify_project_dir(
+        project_dir: &Path,
+    ) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        let mut targets = Vec::new();
+        for entry in project_dir.read_dir()? {
+            let project_file = entry?.path();
+            if !is_project_file(&project_file) || !project_file.is_file() {
+                continue;
+            }
+
+            if let Some(project) = DotNetProject::load(&project_file)? {
+                targets.extend(project.identify_targets());
+            }
+        }
+
+        Ok(targets)
+    }
+}
+
+impl Sweeper for DotNetSweeper {
+    fn name(&self) -> &str {
+        "dotnet"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if is_project_file(path) && path.is_file() {
+            return match DotNetProject::load(path)? {
+                Some(project) => Ok(project.identify_targets()),
+                None => Ok(vec![]),
+            };
+        }
+
+        if ![DEFAULT_OUTPUT_PATH, DEFAULT_INTERMEDIATE_OUTPUT_PATH]
+            .contains(&path.file_name_truncate())
+            || !path.is_dir()
+        {
+            return Ok(vec![]);
+        }
+
+        let Some(project_dir) = path.parent() else {
+            return Ok(vec![]);
+        };
+
+        let targets = Self::identify_project_dir(project_dir)?
+            .into_iter()
+            .filter(|target| target.path() == path)
+            .collect();
+        Ok(targets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{
+        Path,
+        PathBuf,
+    };
+
+    use super::{
+        parse_property,
+        DotNetProject,
+    };
+
+    #[test]
+    fn parses_properties() {
+        let project = r#"<Project Sdk="Microsoft.NET.Sdk">
+  <PropertyGroup>
+    <BaseOutputPathSuffix>ignored</BaseOutputPathSuffix>
+    <BaseOutputPath Condition="'$(Configuration)' == 'Release'">out\</BaseOutputPath>
+  </PropertyGroup>
+</Project>"#;
+        assert_eq!(parse_property(project, "BaseOutputPath"), Some("out\\"));
+        assert_eq!(parse_property(project, "BaseIntermediateOutputPath"), None);
+    }
+
+    #[test]
+    fn parses_empty_elements() {
+        let project = "<BaseOutputPath />\n<BaseIntermediateOutputPath/>\n<Other>x</Other>";
+        assert_eq!(parse_property(project, "BaseOutputPath"), Some(""));
+        assert_eq!(
+            parse_property(project, "BaseIntermediateOutputPath"),
+            Some("")
+        );
+    }
+
+    fn resolve(project: &str) -> Option<PathBuf> {
+        DotNetProject::resolve_path(Path::new("/src/app"), project, "BaseOutputPath", "bin")
+    }
+
+    #[test]
+    fn resolves_paths_within_the_project() {
+        assert_eq!(resolve(""), Some(PathBuf::from("/src/app/bin")));
+        assert_eq!(
+            resolve("<BaseOutputPath />"),
+            Some(PathBuf::from("/src/app/bin"))
+        );
+        assert_eq!(
+            resolve("<BaseOutputPath>build\\..\\out\\</BaseOutputPath>"),
+            Some(PathBuf::from("/src/app/out"))
+        );
+    }
+
+    #[test]
+    fn rejects_paths_outside_the_project() {
+        assert_eq!(
+            resolve("<BaseOutputPath>..\\artifacts</BaseOutputPath>"),
+            None
+        );
+        assert_eq!(resolve("<BaseOutputPath>.</BaseOutputPath>"), None);
+        assert_eq!(resolve("<BaseOutputPath>/tmp/out</BaseOutputPath>"), None);
+        assert_eq!(
+            resolve("<BaseOutputPath>$(ArtifactsPath)</BaseOutputPath>"),
+            None
+        );
+    }
+}
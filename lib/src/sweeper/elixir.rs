@@ -0,0 +1,174 @@
+use std::path::Path;
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::PathEx,
+    target::DirectoryTarget,
+};
+
+/// Build tools of the BEAM ecosystem
+#[derive(Debug, Clone, Copy)]
+enum BuildTool {
+    Mix,
+    Rebar,
+}
+
+impl BuildTool {
+    fn detect(project_dir: &Path) -> Option<Self> {
+        if project_dir.join("mix.exs").is_file() {
+            Some(Self::Mix)
+        } else if project_dir.join("rebar.config").is_file() {
+            Some(Self::Rebar)
+        } else {
+            None
+        }
+    }
+
+    fn lock_file(&self) -> &'static str {
+        match self {
+            Self::Mix => "mix.lock",
+            Self::Rebar => "rebar.lock",
+        }
+    }
+
+    fn build_target_name(&self) -> &'static str {
+        match self {
+            Self::Mix => "mix-build",
+            Self::Rebar => "rebar-build",
+        }
+    }
+
+    fn deps_target_name(&self) -> &'static str {
+        match self {
+            Self::Mix => "mix-deps",
+            Self::Rebar => "rebar-deps",
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ElixirSweeper;
+
+impl ElixirSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Test if the directory contains the compiled applications of at least one build profile,
+    /// e.g. `_build/dev/lib` for mix or `_build/default/lib` for rebar3.
+    fn contains_build_profile(build_dir: &Path) -> Result<bool, SweeperError> {
+        let contains_profile = build_dir
+            .read_dir()?
+            .filter_map(|entry| entry.ok())
+            .any(|entry| entry.path().join("lib").is_dir());
+
+        Ok(contains_profile)
+    }
+}
+
+impl Sweeper for ElixirSweeper {
+    fn name(&self) -> &str {
+        "elixir"
+    }
+
+    fn identify_targets(&self, dir: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !dir.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let Some(project_dir) = dir.parent() else {
+            return Ok(vec![]);
+        };
+
+        let Some(build_tool) = BuildTool::detect(project_dir) else {
+            return Ok(vec![]);
+        };
+
+        /* deps require the network to be restored, the build output does not */
+        let target_name = match dir.file_name_truncate() {
+            "_build" if Self::contains_build_profile(dir)? => build_tool.build_target_name(),
+            "deps" if project_dir.join(build_tool.lock_file()).is_file() => {
+                build_tool.deps_target_name()
+            }
+            _ => return Ok(vec![]),
+        };
+
+        Ok(vec![Box::new(DirectoryTarget::named(
+            target_name,
+            dir.to_owned(),
+        ))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::ElixirSweeper;
+    use crate::Sweeper;
+
+    #[test]
+    fn identifies_build_outputs_and_locked_deps() {
+        let root = env::temp_dir().join(format!("bclean-elixir-{}", process::id()));
+        for (project, build_file, lock_file) in [
+            ("mix", "mix.exs", Some("mix.lock")),
+            ("rebar", "rebar.config", Some("rebar.lock")),
+            ("unlocked", "mix.exs", None),
+        ] {
+            let project = root.join(project);
+            fs::create_dir_all(project.join("_build").join("dev").join("lib")).unwrap();
+            fs::create_dir_all(project.join("deps")).unwrap();
+            fs::write(project.join(build_file), "").unwrap();
+            if let Some(lock_file) = lock_file {
+                fs::write(project.join(lock_file), "").unwrap();
+            }
+        }
+        /* a lock file of the other build tool does not count */
+        fs::write(root.join("unlocked").join("rebar.lock"), "").unwrap();
+
+        let plain = root.join("plain");
+        fs::create_dir_all(plain.join("_build").join("dev").join("lib")).unwrap();
+        fs::create_dir_all(plain.join("deps")).unwrap();
+        fs::write(plain.join("mix.lock"), "").unwrap();
+
+        let sweeper = ElixirSweeper::new();
+        let identify = |path: &str| {
+            sweeper
+                .identify_targets(&root.join(path))
+                .unwrap()
+                .iter()
+                .map(|target| target.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        let targets = [
+            "mix/_build",
+            "mix/deps",
+            "rebar/_build",
+            "rebar/deps",
+            "unlocked/_build",
+        ]
+        .map(identify);
+        let ignored = ["unlocked/deps", "plain/_build", "plain/deps"].map(identify);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            targets,
+            [
+                ["mix-build"],
+                ["mix-deps"],
+                ["rebar-build"],
+                ["rebar-deps"],
+                ["mix-build"],
+            ]
+        );
+        assert!(ignored.iter().all(Vec::is_empty));
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    fs,
+    io,
+    path::Path,
+};
+
+use serde_json::Value;
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::PathEx,
+    target::DirectoryTarget,
+};
+
+/// Sections of the `package.json` declaring dependencies
+const DEPENDENCY_SECTIONS: [&str; 4] = [
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+/// Output or cache directory of a frontend tool
+struct FrontendDir {
+    /// Path of the directory relative to the project directory
+    path: &'static str,
+    target_name: &'static str,
+
+    /// Packages proving the tool is used by the project.
+    /// Entries ending with a `/` match all packages of the scope.
+    packages: &'static [&'static str],
+}
+
+const FRONTEND_DIRS: [FrontendDir; 10] = [
+    FrontendDir {
+        path: ".next",
+        target_name: "next-build",
+        packages: &["next"],
+    },
+    FrontendDir {
+        path: ".nuxt",
+        target_name: "nuxt-build",
+        packages: &["nuxt", "nuxt3"],
+    },
+    FrontendDir {
+        path: ".svelte-kit",
+        target_name: "sveltekit-build",
+        packages: &["@sveltejs/kit"],
+    },
+    FrontendDir {
+        path: ".angular/cache",
+        target_name: "angular-cache",
+        packages: &["@angular/cli", "@angular/core"],
+    },
+    FrontendDir {
+        path: ".parcel-cache",
+        target_name: "parcel-cache",
+        packages: &["parcel", "@parcel/"],
+    },
+    FrontendDir {
+        path: ".turbo",
+        target_name: "turbo-cache",
+        packages: &["turbo"],
+    },
+    FrontendDir {
+        path: ".vite",
+        target_name: "vite-cache",
+        packages: &["vite", "vitest"],
+    },
+    FrontendDir {
+        path: "dist",
+        target_name: "bundler-dist",
+        packages: &[
+            "vite",
+            "webpack",
+            "rollup",
+            "parcel",
+            "esbuild",
+            "tsup",
+            "@vue/cli-service",
+            "@angular/cli",
+        ],
+    },
+    FrontendDir {
+        path: "storybook-static",
+        target_name: "storybook-static",
+        packages: &["storybook", "@storybook/"],
+    },
+    FrontendDir {
+        path: "coverage",
+        target_name: "js-coverage",
+        packages: &["jest", "vitest", "nyc", "c8", "karma-coverage", "@vitest/"],
+    },
+];
+
+/// Returns the names of all dependencies declared by the `package.json`.
+fn parse_dependencies(package: &str) -> Result<Vec<String>, SweeperError> {
+    let package: Value = serde_json::from_str(package)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+    let dependencies = DEPENDENCY_SECTIONS
+        .iter()
+        .filter_map(|section| package.get(section)?.as_object())
+        .flat_map(|dependencies| dependencies.keys().cloned())
+        .collect();
+    Ok(dependencies)
+}
+
+/// Test if the dependency is the package or belongs to the scope of a package ending with a `/`.
+fn matches_package(dependency: &str, package: &str) -> bool {
+    match package.strip_suffix('/') {
+        Some(scope) => dependency
+            .strip_prefix(scope)
+            .is_some_and(|name| name.starts_with('/')),
+        None => dependency == package,
+    }
+}
+
+#[derive(Default)]
+pub struct FrontendSweeper;
+
+impl FrontendSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Test if any of the packages is a dependency of the project.
+    fn uses_any_package(project_dir: &Path, packages: &[&str]) -> Result<bool, SweeperError> {
+        let package_file = project_dir.join("package.json");
+        if !package_file.is_file() {
+            return Ok(false);
+        }
+
+        let dependencies = parse_dependencies(&fs::read_to_string(package_file)?)?;
+        let uses_package = dependencies.iter().any(|dependency| {
+            packages
+                .iter()
+                .any(|package| matches_package(dependency, package))
+        });
+        Ok(uses_package)
+    }
+}
+
+impl Sweeper for FrontendSweeper {
+    fn name(&self) -> &str {
+        "frontend"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let file_name = path.file_name_truncate();
+        for frontend_dir in &FRONTEND_DIRS {
+            if frontend_dir.path.rsplit('/').next() != Some(file_name) {
+                continue;
+            }
+
+            /* resolve the project directory for nested directories like .angular/cache */
+            let depth = frontend_dir.path.split('/').count();
+            let Some(project_dir) = path.ancestors().nth(depth) else {
+                continue;
+            };
+            if project_dir.join(frontend_dir.path) != path {
+                continue;
+            }
+
+            if !Self::uses_any_package(project_dir, frontend_dir.packages)? {
+                continue;
+            }
+
+            return Ok(vec![Box::new(DirectoryTarget::named(
+                frontend_dir.target_name,
+                path.to_owned(),
+            ))]);
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        matches_package,
+        parse_dependencies,
+    };
+
+    #[test]
+    fn parses_dependencies() {
+        let package = r#"{
+            "name": "app",
+            "scripts": {"build": "vite build"},
+            "dependencies": {"react": "^18.2.0"},
+            "devDependencies": {"vite": "^5.0.0", "@storybook/react": "^7.6.0"},
+            "peerDependencies": {"typescript": "*"}
+        }"#;
+        let mut dependencies = parse_dependencies(package).unwrap();
+        dependencies.sort();
+        assert_eq!(
+            dependencies,
+            ["@storybook/react", "react", "typescript", "vite"]
+        );
+
+        assert!(parse_dependencies(r#"{"name": "empty"}"#)
+            .unwrap()
+            .is_empty());
+        assert!(parse_dependencies("{").is_err());
+    }
+
+    #[test]
+    fn matches_packages_and_scopes() {
+        assert!(matches_package("vite", "vite"));
+        assert!(!matches_package("vite-plugin-pwa", "vite"));
+
+        assert!(matches_package("@parcel/core", "@parcel/"));
+        assert!(!matches_package("@parcel", "@parcel/"));
+        assert!(!matches_package("@parcelx/core", "@parcel/"));
+    }
+}
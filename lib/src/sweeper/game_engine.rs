@@ -0,0 +1,196 @@
+use std::path::Path;
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::PathEx,
+    target::DirectoryTarget,
+};
+
+/// Directories generated by the Unity editor
+const UNITY_DIRS: [(&str, &str); 4] = [
+    ("Library", "unity-library"),
+    ("Temp", "unity-temp"),
+    ("Obj", "unity-obj"),
+    ("Logs", "unity-logs"),
+];
+
+/// Directories generated by the Unreal editor and build tool
+const UNREAL_DIRS: [(&str, &str); 4] = [
+    ("Intermediate", "unreal-intermediate"),
+    ("Binaries", "unreal-binaries"),
+    ("Saved", "unreal-saved"),
+    ("DerivedDataCache", "unreal-ddc"),
+];
+
+/// Unreals `Saved` directory contains the user configuration and auto saves
+const UNREAL_RISKY_DIRS: [&str; 1] = ["Saved"];
+
+/// Directory within `Binaries` containing prebuilt libraries which are usually committed
+const UNREAL_THIRD_PARTY_DIR: &str = "ThirdParty";
+
+#[derive(Default)]
+pub struct GameEngineSweeper;
+
+impl GameEngineSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn is_unity_project(path: &Path) -> bool {
+        path.join("Assets").is_dir()
+            && path
+                .join("ProjectSettings")
+                .join("ProjectVersion.txt")
+                .is_file()
+    }
+
+    fn is_unreal_project(path: &Path) -> Result<bool, SweeperError> {
+        let contains_project = path
+            .read_dir()?
+            .filter_map(|entry| entry.ok())
+            .any(|entry| {
+                entry.file_name().to_string_lossy().ends_with(".uproject") && entry.path().is_file()
+            });
+
+        Ok(contains_project)
+    }
+
+    fn is_godot_project(path: &Path) -> bool {
+        path.join("project.godot").is_file()
+    }
+}
+
+impl Sweeper for GameEngineSweeper {
+    fn name(&self) -> &str {
+        "game-engine"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let Some(project_dir) = path.parent() else {
+            return Ok(vec![]);
+        };
+
+        let file_name = path.file_name_truncate();
+        if file_name == ".godot" {
+            if !Self::is_godot_project(project_dir) {
+                return Ok(vec![]);
+            }
+
+            return Ok(vec![Box::new(DirectoryTarget::named(
+                "godot-cache",
+                path.to_owned(),
+            ))]);
+        }
+
+        if let Some((_, target_name)) = UNITY_DIRS.iter().find(|(name, _)| *name == file_name) {
+            if !Self::is_unity_project(project_dir) {
+                return Ok(vec![]);
+            }
+
+            return Ok(vec![Box::new(DirectoryTarget::named(
+                *target_name,
+                path.to_owned(),
+            ))]);
+        }
+
+        if let Some((_, target_name)) = UNREAL_DIRS.iter().find(|(name, _)| *name == file_name) {
+            if !Self::is_unreal_project(project_dir)? {
+                return Ok(vec![]);
+            }
+
+            let target = DirectoryTarget::named(*target_name, path.to_owned());
+            let is_risky = UNREAL_RISKY_DIRS.contains(&file_name)
+                || (file_name == "Binaries" && path.join(UNREAL_THIRD_PARTY_DIR).is_dir());
+            let target = if is_risky { target.risky() } else { target };
+            return Ok(vec![Box::new(target)]);
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::GameEngineSweeper;
+    use crate::Sweeper;
+
+    #[test]
+    fn identifies_engine_outputs() {
+        let root = env::temp_dir().join(format!("bclean-game-engine-{}", process::id()));
+        let unity = root.join("unity");
+        fs::create_dir_all(unity.join("Assets")).unwrap();
+        fs::create_dir_all(unity.join("Library")).unwrap();
+        fs::create_dir_all(unity.join("ProjectSettings")).unwrap();
+        fs::write(unity.join("ProjectSettings").join("ProjectVersion.txt"), "").unwrap();
+
+        let unreal = root.join("unreal");
+        fs::create_dir_all(unreal.join("Intermediate")).unwrap();
+        fs::create_dir_all(unreal.join("Binaries").join("Win64")).unwrap();
+        fs::create_dir_all(unreal.join("Saved")).unwrap();
+        fs::write(unreal.join("Game.uproject"), "{}").unwrap();
+
+        let plugin = root.join("plugin");
+        fs::create_dir_all(plugin.join("Binaries").join("ThirdParty")).unwrap();
+        fs::write(plugin.join("Plugin.uproject"), "{}").unwrap();
+
+        let godot = root.join("godot");
+        fs::create_dir_all(godot.join(".godot")).unwrap();
+        fs::write(godot.join("project.godot"), "").unwrap();
+
+        /* engine directory names within unrelated projects */
+        let plain = root.join("plain");
+        for dir in ["Library", "Binaries", "Saved", ".godot"] {
+            fs::create_dir_all(plain.join(dir)).unwrap();
+        }
+
+        let sweeper = GameEngineSweeper::new();
+        let identify = |path: &str| {
+            sweeper
+                .identify_targets(&root.join(path))
+                .unwrap()
+                .iter()
+                .map(|target| (target.name().to_string(), target.is_risky()))
+                .collect::<Vec<_>>()
+        };
+        let targets = [
+            "unity/Library",
+            "unreal/Intermediate",
+            "unreal/Binaries",
+            "unreal/Saved",
+            "plugin/Binaries",
+            "godot/.godot",
+        ]
+        .map(identify);
+        let plain = ["Library", "Binaries", "Saved", ".godot"]
+            .map(|dir| identify(&format!("plain/{}", dir)));
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            targets,
+            [
+                [("unity-library".to_string(), false)],
+                [("unreal-intermediate".to_string(), false)],
+                [("unreal-binaries".to_string(), false)],
+                [("unreal-saved".to_string(), true)],
+                /* committed third party libraries have to be selected explicitly */
+                [("unreal-binaries".to_string(), true)],
+                [("godot-cache".to_string(), false)],
+            ]
+        );
+        assert!(plain.iter().all(Vec::is_empty));
+    }
+}
@@ -0,0 +1,301 @@
+use std::{
+    env,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    dirs::{
+        cache_dir,
+        data_dir,
+        env_path,
+        home_dir,
+    },
+    target::CacheDirectoryTarget,
+};
+
+/// Returns the build cache directory like `go env GOCACHE` would.
+fn go_build_cache_dir() -> Option<PathBuf> {
+    match env_path("GOCACHE") {
+        /* the build cache has been disabled */
+        Some(path) if path.as_os_str() == "off" => None,
+        Some(path) => Some(path),
+        None => cache_dir().map(|cache_dir| cache_dir.join("go-build")),
+    }
+}
+
+/// Returns the module cache directory like `go env GOMODCACHE` would.
+fn go_module_cache_dir() -> Option<PathBuf> {
+    if let Some(path) = env_path("GOMODCACHE") {
+        return Some(path);
+    }
+
+    /* the module cache is located within the first entry of the GOPATH */
+    let gopath = env::var_os("GOPATH")
+        .and_then(|gopath| env::split_paths(&gopath).find(|path| !path.as_os_str().is_empty()))
+        .or_else(|| home_dir().map(|home| home.join("go")))?;
+    Some(gopath.join("pkg").join("mod"))
+}
+
+fn pulumi_plugins_dir() -> Option<PathBuf> {
+    env_path("PULUMI_HOME")
+        .or_else(|| home_dir().map(|home| home.join(".pulumi")))
+        .map(|pulumi_home| pulumi_home.join("plugins"))
+}
+
+/// Remove all `<!-- -->` comments of a xml document.
+fn strip_xml_comments(xml: &str) -> String {
+    let mut result = String::with_capacity(xml.len());
+    let mut input = xml;
+    while let Some(start) = input.find("<!--") {
+        result.push_str(&input[..start]);
+        input = match input[start..].find("-->") {
+            Some(end) => &input[start + end + "-->".len()..],
+            None => "",
+        };
+    }
+    result.push_str(input);
+    result
+}
+
+/// Extract the `<localRepository>` of the maven settings.
+fn parse_local_repository(settings: &str, home: &Path) -> Option<PathBuf> {
+    let settings = strip_xml_comments(settings);
+    let start = settings.find("<localRepository>")? + "<localRepository>".len();
+    let end = start + settings[start..].find("</localRepository>")?;
+    let repository = settings[start..end].trim();
+    if repository.is_empty() {
+        return None;
+    }
+
+    let repository = repository.replace("${user.home}", &home.to_string_lossy());
+    Some(PathBuf::from(repository))
+}
+
+/// Returns the local maven repository respecting `-Dmaven.repo.local` within `MAVEN_OPTS`
+/// and the `localRepository` of the user settings.
+fn maven_repository_dir() -> Option<PathBuf> {
+    let maven_opts = env::var("MAVEN_OPTS").unwrap_or_default();
+    if let Some(repository) = maven_opts
+        .split_whitespace()
+        .find_map(|option| option.strip_prefix("-Dmaven.repo.local="))
+    {
+        return Some(PathBuf::from(repository));
+    }
+
+    let maven_home = home_dir()?.join(".m2");
+    let configured = fs::read_to_string(maven_home.join("settings.xml"))
+        .ok()
+        .and_then(|settings| parse_local_repository(&settings, maven_home.parent()?));
+    Some(configured.unwrap_or_else(|| maven_home.join("repository")))
+}
+
+/// Toolchains with a global cache which is shared by all projects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobalCache {
+    Cargo,
+    Npm,
+    Pip,
+    Yarn,
+    Pnpm,
+    Gradle,
+    Maven,
+    Go,
+    Terraform,
+    Pulumi,
+}
+
+impl GlobalCache {
+    pub const ALL: [GlobalCache; 10] = [
+        Self::Cargo,
+        Self::Npm,
+        Self::Pip,
+        Self::Yarn,
+        Self::Pnpm,
+        Self::Gradle,
+        Self::Maven,
+        Self::Go,
+        Self::Terraform,
+        Self::Pulumi,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Cargo => "cargo",
+            Self::Npm => "npm",
+            Self::Pip => "pip",
+            Self::Yarn => "yarn",
+            Self::Pnpm => "pnpm",
+            Self::Gradle => "gradle",
+            Self::Maven => "maven",
+            Self::Go => "go",
+            Self::Terraform => "terraform",
+            Self::Pulumi => "pulumi",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|cache| cache.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the target names and locations of the cache directories.
+    /// The locations respect the environment variables of the toolchain.
+    fn directories(&self) -> Vec<(&'static str, PathBuf)> {
+        let directories = match self {
+            Self::Cargo => {
+                let Some(cargo_home) =
+                    env_path("CARGO_HOME").or_else(|| home_dir().map(|home| home.join(".cargo")))
+                else {
+                    return vec![];
+                };
+
+                return vec![
+                    ("cargo-registry", cargo_home.join("registry")),
+                    ("cargo-git", cargo_home.join("git")),
+                ];
+            }
+            Self::Npm => env_path("npm_config_cache")
+                .or_else(|| {
+                    if cfg!(windows) {
+                        cache_dir().map(|cache_dir| cache_dir.join("npm-cache"))
+                    } else {
+                        home_dir().map(|home| home.join(".npm"))
+                    }
+                })
+                .map(|npm_cache| ("npm-cache", npm_cache.join("_cacache"))),
+            Self::Pip => env_path("PIP_CACHE_DIR")
+                .or_else(|| cache_dir().map(|cache_dir| cache_dir.join("pip")))
+                .map(|pip_cache| ("pip-cache", pip_cache)),
+            Self::Yarn => env_path("YARN_CACHE_FOLDER")
+                .or_else(|| cache_dir().map(|cache_dir| cache_dir.join("yarn")))
+                .map(|yarn_cache| ("yarn-cache", yarn_cache)),
+            Self::Pnpm => env_path("PNPM_STORE_DIR")
+                .or_else(|| data_dir().map(|data_dir| data_dir.join("pnpm").join("store")))
+                .map(|pnpm_store| ("pnpm-store", pnpm_store)),
+            Self::Gradle => env_path("GRADLE_USER_HOME")
+                .or_else(|| home_dir().map(|home| home.join(".gradle")))
+                .map(|gradle_home| ("gradle-caches", gradle_home.join("caches"))),
+            Self::Maven => {
+                maven_repository_dir().map(|repository| ("maven-repository", repository))
+            }
+            /* the module cache contains read-only files which is handled by the cache target */
+            Self::Go => {
+                return [
+                    go_build_cache_dir().map(|build_cache| ("go-build-cache", build_cache)),
+                    go_module_cache_dir().map(|module_cache| ("go-module-cache", module_cache)),
+                ]
+                .into_iter()
+                .flatten()
+                .collect();
+            }
+            Self::Terraform => env_path("TF_PLUGIN_CACHE_DIR")
+                .map(|plugin_cache| ("terraform-plugin-cache", plugin_cache)),
+            Self::Pulumi => pulumi_plugins_dir().map(|plugins| ("pulumi-plugins", plugins)),
+        };
+
+        directories.into_iter().collect()
+    }
+}
+
+/// Reports the global caches of the selected toolchains independently of the scanned directory.
+pub struct GlobalCacheSweeper {
+    caches: Vec<GlobalCache>,
+}
+
+impl GlobalCacheSweeper {
+    pub fn new() -> Self {
+        Self::with_caches(GlobalCache::ALL.to_vec())
+    }
+
+    pub fn with_caches(caches: Vec<GlobalCache>) -> Self {
+        Self { caches }
+    }
+}
+
+impl Sweeper for GlobalCacheSweeper {
+    fn name(&self) -> &str {
+        "global-cache"
+    }
+
+    fn identify_targets(
+        &self,
+        _path: &Path,
+    ) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        Ok(vec![])
+    }
+
+    fn identify_global_targets(&self) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        let targets = self
+            .caches
+            .iter()
+            .flat_map(|cache| cache.directories())
+            .filter(|(_, path)| path.is_dir())
+            .map(|(name, path)| {
+                Box::new(CacheDirectoryTarget::new(name, path)) as Box<dyn SweepableTarget>
+            })
+            .collect();
+
+        Ok(targets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{
+        Path,
+        PathBuf,
+    };
+
+    use super::{
+        parse_local_repository,
+        GlobalCache,
+    };
+
+    #[test]
+    fn parses_the_local_repository() {
+        let home = Path::new("/home/user");
+        let settings = r#"<settings>
+  <!-- localRepository
+   | The path to the local repository maven will use to store artifacts.
+  <localRepository>/path/to/local/repo</localRepository>
+  -->
+  <localRepository>${user.home}/maven/repository</localRepository>
+</settings>"#;
+        assert_eq!(
+            parse_local_repository(settings, home),
+            Some(PathBuf::from("/home/user/maven/repository"))
+        );
+
+        assert_eq!(
+            parse_local_repository(
+                "<settings><!-- <localRepository>/repo</localRepository> --></settings>",
+                home
+            ),
+            None
+        );
+        assert_eq!(
+            parse_local_repository(
+                "<settings><localRepository> </localRepository></settings>",
+                home
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn finds_caches_by_name() {
+        assert_eq!(GlobalCache::from_name("Cargo"), Some(GlobalCache::Cargo));
+        assert_eq!(GlobalCache::from_name("go"), Some(GlobalCache::Go));
+        assert_eq!(GlobalCache::from_name("cargo-registry"), None);
+    }
+}
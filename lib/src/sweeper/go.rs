@@ -0,0 +1,223 @@
+use std::{
+    fs,
+    path::Path,
+};
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::PathEx,
+    target::DirectoryTarget,
+};
+
+const MAKEFILES: [&str; 3] = ["GNUmakefile", "makefile", "Makefile"];
+
+#[derive(Default)]
+pub struct GoSweeper;
+
+impl GoSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn is_module_dir(path: &Path) -> bool {
+        path.join("go.mod").is_file()
+    }
+
+    /// Test if any makefile of the module writes its outputs into `bin`.
+    fn is_makefile_output(module_dir: &Path) -> Result<bool, SweeperError> {
+        for makefile in MAKEFILES {
+            let makefile = module_dir.join(makefile);
+            if !makefile.is_file() {
+                continue;
+            }
+
+            if has_bin_output_rule(&fs::read_to_string(&makefile)?) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Test if the directory only contains executables built after the last change of the `go.mod`.
+    fn contains_only_build_outputs(
+        bin_dir: &Path,
+        module_dir: &Path,
+    ) -> Result<bool, SweeperError> {
+        let module_modified = fs::metadata(module_dir.join("go.mod"))?.modified()?;
+
+        let mut contains_outputs = false;
+        for entry in fs::read_dir(bin_dir)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if !metadata.is_file()
+                || !is_executable(&entry.path(), &metadata)
+                || metadata.modified()? < module_modified
+            {
+                return Ok(false);
+            }
+            contains_outputs = true;
+        }
+
+        Ok(contains_outputs)
+    }
+}
+
+/// Test if a makefile contains a rule writing its output into `bin`, e.g. `go build -o bin/app`.
+/// References like `/usr/bin/env` or `$(GOPATH)/bin/tool` are not outputs of the module.
+fn has_bin_output_rule(makefile: &str) -> bool {
+    let is_bin_path = |path: &str| {
+        let path = path.trim_matches(|c| c == '"' || c == '\'');
+        let path = path.strip_prefix("./").unwrap_or(path);
+        path == "bin" || path.starts_with("bin/")
+    };
+
+    makefile.lines().any(|line| {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut arguments = line.split_whitespace();
+        while let Some(argument) = arguments.next() {
+            let output = match argument.strip_prefix("-o") {
+                Some("") => arguments.next(),
+                Some(output) => output.strip_prefix('='),
+                None => None,
+            };
+            if output.is_some_and(is_bin_path) {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+#[cfg(unix)]
+fn is_executable(_path: &Path, metadata: &fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path, _metadata: &fs::Metadata) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("exe"))
+}
+
+impl Sweeper for GoSweeper {
+    fn name(&self) -> &str {
+        "go"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let Some(module_dir) = path.parent() else {
+            return Ok(vec![]);
+        };
+        if !Self::is_module_dir(module_dir) {
+            return Ok(vec![]);
+        }
+
+        let target_name = match path.file_name_truncate() {
+            /* go mod vendor always writes the modules.txt */
+            "vendor" if path.join("modules.txt").is_file() => "go-vendor",
+            /* committed scripts or tools next to the outputs must never be removed */
+            "bin"
+                if Self::is_makefile_output(module_dir)?
+                    && Self::contains_only_build_outputs(path, module_dir)? =>
+            {
+                "go-bin"
+            }
+            _ => return Ok(vec![]),
+        };
+
+        Ok(vec![Box::new(DirectoryTarget::named(
+            target_name,
+            path.to_owned(),
+        ))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::{
+        has_bin_output_rule,
+        GoSweeper,
+    };
+    use crate::Sweeper;
+
+    #[test]
+    fn detects_output_rules() {
+        assert!(has_bin_output_rule(
+            "build:\n\tgo build -o bin/app ./cmd/app\n"
+        ));
+        assert!(has_bin_output_rule("build:\n\tgo build -o ./bin ./...\n"));
+        assert!(has_bin_output_rule("build:\n\tgo build -o=bin/app .\n"));
+        assert!(has_bin_output_rule("build:\n\tgo build -o \"bin/app\" .\n"));
+    }
+
+    #[test]
+    fn ignores_other_bin_references() {
+        assert!(!has_bin_output_rule("SHELL := /bin/bash\n"));
+        assert!(!has_bin_output_rule("#!/usr/bin/env make\n"));
+        assert!(!has_bin_output_rule(
+            "lint:\n\t$(GOPATH)/bin/golangci-lint run\n"
+        ));
+        assert!(!has_bin_output_rule("run:\n\t./bin/app\n"));
+        assert!(!has_bin_output_rule("build:\n\tgo build . # -o bin/app\n"));
+        assert!(!has_bin_output_rule(
+            "build:\n\tgo build -o out/bin/app .\n"
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn identifies_bin_only_with_build_outputs() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let root = env::temp_dir().join(format!("bclean-go-{}", process::id()));
+        for project in ["outputs", "scripts", "no-rule"] {
+            let module_dir = root.join(project);
+            fs::create_dir_all(module_dir.join("bin")).unwrap();
+            fs::write(module_dir.join("go.mod"), "module example.com/app\n").unwrap();
+            if project != "no-rule" {
+                fs::write(
+                    module_dir.join("Makefile"),
+                    "build:\n\tgo build -o bin/app .\n",
+                )
+                .unwrap();
+            }
+
+            let app = module_dir.join("bin").join("app");
+            fs::write(&app, "").unwrap();
+            fs::set_permissions(&app, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        fs::write(root.join("scripts").join("bin").join("setup.sh"), "").unwrap();
+
+        let sweeper = GoSweeper::new();
+        let identify = |project: &str| {
+            sweeper
+                .identify_targets(&root.join(project).join("bin"))
+                .unwrap()
+                .len()
+        };
+        let results = [
+            identify("outputs"),
+            identify("scripts"),
+            identify("no-rule"),
+        ];
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(results, [1, 0, 0]);
+    }
+}
@@ -0,0 +1,407 @@
+use std::{
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::{
+        normalize_relative,
+        PathEx,
+    },
+    target::DirectoryTarget,
+};
+
+const SETTINGS_FILES: [&str; 2] = ["settings.gradle", "settings.gradle.kts"];
+const BUILD_FILES: [&str; 2] = ["build.gradle", "build.gradle.kts"];
+
+/// Maximum number of parent directories to search for the settings file of a module
+const MAX_MODULE_DEPTH: usize = 8;
+
+/// Parse a string literal at the beginning of the input.
+/// Returns the string value and the remaining input.
+fn parse_string_literal(input: &str) -> Option<(&str, &str)> {
+    let quote = input.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let input = &input[1..];
+    let end = input.find(quote)?;
+    Some((&input[..end], &input[end + 1..]))
+}
+
+/// Skip a string literal including triple quoted strings at the beginning of the input.
+/// Returns the input after the closing quote.
+fn skip_string_literal(input: &str) -> &str {
+    if !input.starts_with(['"', '\'']) {
+        return input;
+    }
+    let delimiter = if input.starts_with("\"\"\"") || input.starts_with("'''") {
+        &input[..3]
+    } else {
+        &input[..1]
+    };
+
+    let content = &input[delimiter.len()..];
+    let mut escaped = false;
+    for (index, c) in content.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if content[index..].starts_with(delimiter) {
+            return &content[index + delimiter.len()..];
+        }
+    }
+    ""
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Strip the expected token and all leading whitespaces.
+fn strip_token<'a>(input: &'a str, token: &str) -> Option<&'a str> {
+    input.trim_start().strip_prefix(token)
+}
+
+/// Convert a gradle project path like `:libs:core` into the default project directory `libs/core`.
+fn project_path_to_dir(project_path: &str) -> PathBuf {
+    project_path
+        .split(':')
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// Module directories of a multi project build relative to the settings file.
+/// Modules located outside of the project directory are omitted.
+#[derive(Debug, Default)]
+struct GradleSettings {
+    modules: Vec<PathBuf>,
+}
+
+impl GradleSettings {
+    fn parse(content: &str) -> Self {
+        let mut modules = Vec::new();
+        let mut project_dirs = Vec::new();
+
+        let mut input = content;
+        while !input.is_empty() {
+            if input.starts_with("//") {
+                /* skip line comments */
+                input = input.find('\n').map_or("", |index| &input[index..]);
+                continue;
+            }
+
+            if let Some(comment) = input.strip_prefix("/*") {
+                /* skip block comments */
+                input = comment.find("*/").map_or("", |index| &comment[index + 2..]);
+                continue;
+            }
+
+            if input.starts_with(['"', '\'']) {
+                /* strings may contain comment tokens, e.g. within urls */
+                input = skip_string_literal(input);
+                continue;
+            }
+
+            if let Some(mut remaining) = input.strip_prefix("include") {
+                /* includeBuild and includeFlat do not declare modules of this build */
+                if !remaining.starts_with(is_identifier_char) {
+                    remaining = remaining.trim_start();
+                    remaining = remaining.strip_prefix('(').unwrap_or(remaining);
+
+                    loop {
+                        remaining =
+                            remaining.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+                        let Some((project_path, rest)) = parse_string_literal(remaining) else {
+                            break;
+                        };
+
+                        modules.push(project_path.to_string());
+                        remaining = rest;
+                    }
+
+                    input = remaining;
+                    continue;
+                }
+            }
+
+            if let Some(project_dir) = Self::parse_project_dir(input) {
+                project_dirs.push(project_dir);
+            }
+
+            /* skip whole identifiers, keywords are never part of a longer identifier */
+            let identifier_length = input
+                .find(|c: char| !is_identifier_char(c))
+                .unwrap_or(input.len());
+            input = if identifier_length > 0 {
+                &input[identifier_length..]
+            } else {
+                let mut chars = input.chars();
+                chars.next();
+                chars.as_str()
+            };
+        }
+
+        let modules = modules
+            .into_iter()
+            .map(|project_path| {
+                project_dirs
+                    .iter()
+                    .find(|(path, _)| {
+                        path.trim_start_matches(':') == project_path.trim_start_matches(':')
+                    })
+                    .map(|(_, dir)| dir.clone())
+                    .unwrap_or_else(|| project_path_to_dir(&project_path))
+            })
+            /* modules outside of the project directory belong to another project tree */
+            .filter_map(|module| normalize_relative(&module))
+            .collect();
+
+        Self { modules }
+    }
+
+    /// Parse a custom project directory declaration:
+    /// `project(":name").projectDir = file("path")`
+    fn parse_project_dir(input: &str) -> Option<(String, PathBuf)> {
+        let input = input.strip_prefix("project")?;
+        let input = strip_token(input, "(")?;
+        let (project_path, input) = parse_string_literal(input.trim_start())?;
+        let input = strip_token(input, ")")?;
+        let input = strip_token(input, ".projectDir")?;
+        let input = strip_token(input, "=")?;
+        let input = strip_token(input, "file")?;
+        let input = strip_token(input, "(")?;
+        let (project_dir, _) = parse_string_literal(input.trim_start())?;
+
+        Some((project_path.to_string(), PathBuf::from(project_dir)))
+    }
+
+    fn load(project_root: &Path) -> io::Result<Option<Self>> {
+        for settings_file in SETTINGS_FILES {
+            match fs::read_to_string(project_root.join(settings_file)) {
+                Ok(content) => return Ok(Some(Self::parse(&content))),
+                Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[derive(Default)]
+pub struct GradleSweeper;
+
+impl GradleSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn build_target(module_dir: &Path) -> Option<Box<dyn SweepableTarget>> {
+        let build_dir = module_dir.join("build");
+        if !build_dir.is_dir() {
+            return None;
+        }
+
+        Some(Box::new(DirectoryTarget::named("gradle-build", build_dir)))
+    }
+
+    fn cache_target(project_dir: &Path) -> Option<Box<dyn SweepableTarget>> {
+        let cache_dir = project_dir.join(".gradle");
+        if !cache_dir.is_dir() {
+            return None;
+        }
+
+        Some(Box::new(DirectoryTarget::named("gradle-cache", cache_dir)))
+    }
+
+    /// Identify the build outputs of all modules of the project
+    fn identify_project(
+        project_root: &Path,
+    ) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        let settings = match GradleSettings::load(project_root)? {
+            Some(settings) => settings,
+            None if project_root.contains_any_file(&BUILD_FILES) => GradleSettings::default(),
+            None => return Ok(vec![]),
+        };
+
+        let mut targets = Vec::new();
+        targets.extend(Self::cache_target(project_root));
+        targets.extend(Self::build_target(project_root));
+
+        /* buildSrc is an implicitly included build. Only its outputs will be removed. */
+        let build_src = project_root.join("buildSrc");
+        targets.extend(Self::cache_target(&build_src));
+        targets.extend(Self::build_target(&build_src));
+
+        for module in settings.modules {
+            let module_dir = project_root.join(module);
+            if module_dir == project_root {
+                continue;
+            }
+
+            targets.extend(Self::build_target(&module_dir));
+        }
+
+        Ok(targets)
+    }
+
+    /// Find the root directory of the project the module belongs to.
+    fn find_project_root(module_dir: &Path) -> Option<&Path> {
+        module_dir
+            .ancestors()
+            .take(MAX_MODULE_DEPTH)
+            .find(|dir| dir.contains_any_file(&SETTINGS_FILES))
+    }
+
+    /// Test if the build directory is the output of a gradle module
+    fn is_module_build(build_dir: &Path) -> Result<bool, SweeperError> {
+        let Some(module_dir) = build_dir.parent() else {
+            return Ok(false);
+        };
+
+        if module_dir.contains_any_file(&BUILD_FILES) {
+            return Ok(true);
+        }
+
+        let Some(project_root) = Self::find_project_root(module_dir) else {
+            return Ok(false);
+        };
+
+        if module_dir == project_root.join("buildSrc") {
+            return Ok(true);
+        }
+
+        let Some(settings) = GradleSettings::load(project_root)? else {
+            return Ok(false);
+        };
+
+        Ok(settings
+            .modules
+            .iter()
+            .any(|module| project_root.join(module) == module_dir))
+    }
+}
+
+impl Sweeper for GradleSweeper {
+    fn name(&self) -> &str {
+        "gradle"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+
+        match path.file_name_truncate() {
+            "build" => {
+                if !Self::is_module_build(path)? {
+                    return Ok(vec![]);
+                }
+
+                Ok(vec![Box::new(DirectoryTarget::named(
+                    "gradle-build",
+                    path.to_owned(),
+                ))])
+            }
+            ".gradle" => {
+                let Some(project_dir) = path.parent() else {
+                    return Ok(vec![]);
+                };
+
+                if !project_dir.contains_any_file(&SETTINGS_FILES)
+                    && !project_dir.contains_any_file(&BUILD_FILES)
+                {
+                    return Ok(vec![]);
+                }
+
+                Ok(vec![Box::new(DirectoryTarget::named(
+                    "gradle-cache",
+                    path.to_owned(),
+                ))])
+            }
+            _ if path.contains_any_file(&SETTINGS_FILES)
+                || path.contains_any_file(&BUILD_FILES) =>
+            {
+                Self::identify_project(path)
+            }
+            _ => Ok(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::GradleSettings;
+
+    fn modules(settings: &str) -> Vec<PathBuf> {
+        GradleSettings::parse(settings).modules
+    }
+
+    #[test]
+    fn parses_included_modules() {
+        assert_eq!(
+            modules("rootProject.name = 'app'\ninclude ':app', ':libs:core'\n"),
+            [PathBuf::from("app"), PathBuf::from("libs/core")]
+        );
+        assert_eq!(
+            modules("include(\":app\")\ninclude(\"lib\")\n"),
+            [PathBuf::from("app"), PathBuf::from("lib")]
+        );
+        assert_eq!(
+            modules("includeBuild 'plugins'\nincludeFlat 'sibling'\n"),
+            Vec::<PathBuf>::new()
+        );
+    }
+
+    #[test]
+    fn applies_custom_project_dirs() {
+        let settings = "include ':core'\nproject(':core').projectDir = file('libs/./core')\n";
+        assert_eq!(modules(settings), [PathBuf::from("libs/core")]);
+    }
+
+    #[test]
+    fn omits_modules_outside_of_the_project() {
+        let settings =
+            "include ':shared', ':app'\nproject(':shared').projectDir = file('../shared')\n";
+        assert_eq!(modules(settings), [PathBuf::from("app")]);
+    }
+
+    #[test]
+    fn skips_comments() {
+        let settings =
+            "// include ':line'\n/* include ':block'\ninclude ':nested' */\ninclude ':app'\n";
+        assert_eq!(modules(settings), [PathBuf::from("app")]);
+    }
+
+    #[test]
+    fn skips_string_literals() {
+        let settings = r#"
+def url = 'https://example.com'; include ':after-url'
+description = "include ':quoted' \" // include ':escaped'"
+notes = """
+include ':triple'
+"""
+include ':app'
+"#;
+        assert_eq!(
+            modules(settings),
+            [PathBuf::from("after-url"), PathBuf::from("app")]
+        );
+    }
+
+    #[test]
+    fn matches_whole_identifiers() {
+        let settings = "shouldinclude ':other'\nmy_include(':other')\ninclude ':app'\n";
+        assert_eq!(modules(settings), [PathBuf::from("app")]);
+    }
+}
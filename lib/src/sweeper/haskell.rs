@@ -0,0 +1,134 @@
+use std::path::Path;
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::PathEx,
+    target::DirectoryTarget,
+};
+
+/// Entries of `.stack-work` which are created by every stack build
+const STACK_WORK_MARKERS: [&str; 2] = ["dist", "install"];
+
+#[derive(Default)]
+pub struct HaskellSweeper;
+
+impl HaskellSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn is_cabal_project(path: &Path) -> Result<bool, SweeperError> {
+        if path.join("cabal.project").is_file() {
+            return Ok(true);
+        }
+
+        let contains_package = path
+            .read_dir()?
+            .filter_map(|entry| entry.ok())
+            .any(|entry| {
+                entry.file_name().to_string_lossy().ends_with(".cabal") && entry.path().is_file()
+            });
+        Ok(contains_package)
+    }
+
+    fn is_cabal_dist(dir: &Path, project_dir: &Path) -> Result<bool, SweeperError> {
+        /* cabal stores the build plan within the cache of the dist directory */
+        if !dir.join("cache").join("plan.json").is_file() {
+            return Ok(false);
+        }
+
+        Self::is_cabal_project(project_dir)
+    }
+
+    fn is_stack_work(dir: &Path, project_dir: &Path) -> bool {
+        if !project_dir.join("stack.yaml").is_file() {
+            return false;
+        }
+
+        STACK_WORK_MARKERS
+            .iter()
+            .any(|marker| dir.join(marker).is_dir())
+    }
+}
+
+impl Sweeper for HaskellSweeper {
+    fn name(&self) -> &str {
+        "haskell"
+    }
+
+    fn identify_targets(&self, dir: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !dir.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let Some(project_dir) = dir.parent() else {
+            return Ok(vec![]);
+        };
+
+        let target_name = match dir.file_name_truncate() {
+            "dist-newstyle" if Self::is_cabal_dist(dir, project_dir)? => "cabal-dist",
+            ".stack-work" if Self::is_stack_work(dir, project_dir) => "stack-work",
+            _ => return Ok(vec![]),
+        };
+
+        Ok(vec![Box::new(DirectoryTarget::named(
+            target_name,
+            dir.to_owned(),
+        ))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::HaskellSweeper;
+    use crate::Sweeper;
+
+    #[test]
+    fn identifies_cabal_and_stack_outputs() {
+        let root = env::temp_dir().join(format!("bclean-haskell-{}", process::id()));
+        let cabal = root.join("cabal");
+        fs::create_dir_all(cabal.join("dist-newstyle").join("cache")).unwrap();
+        fs::write(cabal.join("demo.cabal"), "").unwrap();
+        fs::write(
+            cabal.join("dist-newstyle").join("cache").join("plan.json"),
+            "{}",
+        )
+        .unwrap();
+
+        let stack = root.join("stack");
+        fs::create_dir_all(stack.join(".stack-work").join("install")).unwrap();
+        fs::write(stack.join("stack.yaml"), "").unwrap();
+
+        /* build directories without a build plan or a stack project */
+        let plain = root.join("plain");
+        fs::create_dir_all(plain.join("dist-newstyle").join("cache")).unwrap();
+        fs::create_dir_all(plain.join(".stack-work").join("install")).unwrap();
+        fs::write(plain.join("plain.cabal"), "").unwrap();
+
+        let sweeper = HaskellSweeper::new();
+        let identify = |path: &str| sweeper.identify_targets(&root.join(path)).unwrap();
+        let cabal = identify("cabal/dist-newstyle");
+        let stack = identify("stack/.stack-work");
+        let plain = [
+            identify("plain/dist-newstyle"),
+            identify("plain/.stack-work"),
+        ];
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(cabal.len(), 1);
+        assert_eq!(cabal[0].name(), "cabal-dist");
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack[0].name(), "stack-work");
+        assert!(plain.iter().all(Vec::is_empty));
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    ffi::OsStr,
+    fs,
+    io::Read,
+    path::Path,
+};
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::target::FileSetTarget;
+
+/// Auxiliary files created by LaTeX, BibTeX, latexmk and SyncTeX for a document
+const AUX_EXTENSIONS: [&str; 9] = [
+    "aux",
+    "log",
+    "out",
+    "toc",
+    "fls",
+    "fdb_latexmk",
+    "synctex.gz",
+    "bbl",
+    "blg",
+];
+
+/// Number of bytes read to find the document class of a main document
+const PREAMBLE_LENGTH: u64 = 64 * 1024;
+
+/// Test if the preamble declares a document class outside of a comment.
+fn declares_document_class(preamble: &str) -> bool {
+    preamble.lines().any(|line| {
+        let line = line.split('%').next().unwrap_or_default();
+        line.contains("\\documentclass")
+    })
+}
+
+#[derive(Default)]
+pub struct LatexSweeper;
+
+impl LatexSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Test if the `.tex` file is a main document and not only included by another document.
+    fn is_main_document(path: &Path) -> Result<bool, SweeperError> {
+        let mut preamble = Vec::new();
+        fs::File::open(path)?
+            .take(PREAMBLE_LENGTH)
+            .read_to_end(&mut preamble)?;
+
+        Ok(declares_document_class(&String::from_utf8_lossy(&preamble)))
+    }
+}
+
+impl Sweeper for LatexSweeper {
+    fn name(&self) -> &str {
+        "latex"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if path.extension() != Some(OsStr::new("tex")) || !path.is_file() {
+            return Ok(vec![]);
+        }
+
+        let (Some(dir), Some(stem)) = (path.parent(), path.file_stem()) else {
+            return Ok(vec![]);
+        };
+        let stem = stem.to_string_lossy();
+
+        let mut files = AUX_EXTENSIONS
+            .iter()
+            .map(|extension| dir.join(format!("{}.{}", stem, extension)))
+            .filter(|file| file.is_file())
+            .collect::<Vec<_>>();
+
+        let minted_dir = dir.join(format!("_minted-{}", stem));
+        if minted_dir.is_dir() && !minted_dir.is_symlink() {
+            files.push(minted_dir);
+        }
+
+        if files.is_empty() || !Self::is_main_document(path)? {
+            return Ok(vec![]);
+        }
+
+        /* the target is identified by the document which itself is not part of the file set */
+        Ok(vec![Box::new(FileSetTarget::new(
+            "latex-aux",
+            path.to_owned(),
+            files,
+        ))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::declares_document_class;
+
+    #[test]
+    fn detects_main_documents() {
+        assert!(declares_document_class(
+            "\\documentclass[a4paper]{article}\n\\begin{document}\n"
+        ));
+        assert!(declares_document_class(
+            "% !TEX program = lualatex\n  \\documentclass{beamer}\n"
+        ));
+    }
+
+    #[test]
+    fn ignores_included_documents() {
+        assert!(!declares_document_class(
+            "\\section{Introduction}\nSee \\cite{x}.\n"
+        ));
+        assert!(!declares_document_class(
+            "% use \\documentclass{article} when compiling alone\n\\section{Results}\n"
+        ));
+    }
+}
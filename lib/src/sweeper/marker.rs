@@ -0,0 +1,86 @@
+use std::path::Path;
+
+use super::{
+    SweepableTarget,
+    SweeperError,
+};
+use crate::{
+    path::PathEx,
+    target::DirectoryTarget,
+};
+
+/// Identify a build output directory by its name and a marker file next to it,
+/// e.g. the `zig-out` directory next to a `build.zig`.
+/// The target directories are given as pairs of directory name and target name.
+pub(crate) fn identify_marked_dir(
+    path: &Path,
+    marker_file: &str,
+    target_dirs: &[(&str, &str)],
+) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+    if !path.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let Some((_, target_name)) = target_dirs
+        .iter()
+        .find(|(dir_name, _)| *dir_name == path.file_name_truncate())
+    else {
+        return Ok(vec![]);
+    };
+
+    let parent = match path.parent() {
+        Some(parent) => parent,
+        None => return Ok(vec![]),
+    };
+    if !parent
+        .contains_file_ignore_case(marker_file)
+        .unwrap_or(false)
+    {
+        return Ok(vec![]);
+    }
+
+    Ok(vec![Box::new(DirectoryTarget::named(
+        *target_name,
+        path.to_owned(),
+    ))])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        path::PathBuf,
+        process,
+    };
+
+    use super::identify_marked_dir;
+
+    const TARGET_DIRS: [(&str, &str); 2] = [("out", "test-out"), (".cache", "test-cache")];
+
+    #[test]
+    fn identifies_dirs_next_to_the_marker() {
+        let root = env::temp_dir().join(format!("bclean-marker-{}", process::id()));
+        let marked = root.join("marked");
+        let unmarked = root.join("unmarked");
+        fs::create_dir_all(marked.join("out")).unwrap();
+        fs::create_dir_all(marked.join("src")).unwrap();
+        fs::create_dir_all(unmarked.join("out")).unwrap();
+        fs::write(marked.join("Build.Marker"), "").unwrap();
+        fs::write(marked.join(".cache"), "").unwrap();
+
+        let identify =
+            |path: PathBuf| identify_marked_dir(&path, "build.marker", &TARGET_DIRS).unwrap();
+        let marked_out = identify(marked.join("out"));
+        let marked_src = identify(marked.join("src"));
+        let marked_cache_file = identify(marked.join(".cache"));
+        let unmarked_out = identify(unmarked.join("out"));
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(marked_out.len(), 1);
+        assert_eq!(marked_out[0].name(), "test-out");
+        assert!(marked_src.is_empty());
+        assert!(marked_cache_file.is_empty());
+        assert!(unmarked_out.is_empty());
+    }
+}
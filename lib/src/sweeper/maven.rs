@@ -0,0 +1,302 @@
+use std::{
+    collections::HashSet,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::{
+        normalize_relative,
+        PathEx,
+    },
+    target::{
+        DirectoryTarget,
+        FileSetTarget,
+    },
+};
+
+/// Entries created by the maven build lifecycle and its default plugins
+const MAVEN_MARKERS: [&str; 7] = [
+    "maven-status",
+    "maven-archiver",
+    "classes",
+    "test-classes",
+    "generated-sources",
+    "surefire-reports",
+    "failsafe-reports",
+];
+
+/// Entries which indicate the target directory belongs to another tool, e.g. cargo
+const FOREIGN_MARKERS: [&str; 2] = ["CACHEDIR.TAG", ".rustc_info.json"];
+
+/// Maximum number of parent directories to search for the reactor of a module
+const MAX_REACTOR_DEPTH: usize = 8;
+
+/// Extract all `<module>` entries of the pom.
+fn parse_modules(pom: &str) -> Vec<String> {
+    let mut modules = Vec::new();
+
+    let mut input = pom;
+    while let Some(start) = input.find("<modules>") {
+        input = &input[start + "<modules>".len()..];
+        let end = input.find("</modules>").unwrap_or(input.len());
+        let mut module_list = &input[..end];
+        input = &input[end..];
+
+        while let Some(start) = module_list.find("<module>") {
+            module_list = &module_list[start + "<module>".len()..];
+            let Some(end) = module_list.find("</module>") else {
+                break;
+            };
+
+            modules.push(module_list[..end].trim().to_string());
+            module_list = &module_list[end..];
+        }
+    }
+
+    modules
+}
+
+#[derive(Default)]
+pub struct MavenSweeper;
+
+impl MavenSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Test if the target directory has been created by maven and not by another tool.
+    fn is_maven_target(target_dir: &Path) -> bool {
+        if FOREIGN_MARKERS
+            .iter()
+            .any(|marker| target_dir.join(marker).exists())
+        {
+            return false;
+        }
+
+        MAVEN_MARKERS
+            .iter()
+            .any(|marker| target_dir.join(marker).exists())
+    }
+
+    fn module_target_dir(module_dir: &Path) -> Option<PathBuf> {
+        let target_dir = module_dir.join("target");
+        if !target_dir.is_dir() || target_dir.is_symlink() || !Self::is_maven_target(&target_dir) {
+            return None;
+        }
+
+        Some(target_dir)
+    }
+
+    /// Resolve the directories of the modules declared by the pom within the module directory.
+    /// Modules outside of the module directory belong to another project tree.
+    fn declared_modules(module_dir: &Path, pom: &str) -> Vec<PathBuf> {
+        parse_modules(pom)
+            .iter()
+            .filter_map(|module| normalize_relative(Path::new(module)))
+            .filter(|module| !module.as_os_str().is_empty())
+            .map(|module| {
+                /* a module may reference a pom file instead of a directory */
+                let module = module_dir.join(module);
+                match module.parent() {
+                    Some(parent) if module.is_file() => parent.to_owned(),
+                    _ => module,
+                }
+            })
+            .collect()
+    }
+
+    /// Collect the directories of all modules of the reactor including the reactor itself.
+    fn reactor_modules(reactor_dir: &Path) -> Result<Vec<PathBuf>, SweeperError> {
+        let mut modules = Vec::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![reactor_dir.to_owned()];
+
+        while let Some(module_dir) = pending.pop() {
+            if !visited.insert(module_dir.clone()) {
+                continue;
+            }
+
+            let pom_path = module_dir.join("pom.xml");
+            let pom = match fs::read_to_string(&pom_path) {
+                Ok(pom) => pom,
+                Err(error) if module_dir == reactor_dir => return Err(error.into()),
+                Err(error) => {
+                    log::debug!(
+                        "Failed to read maven module {}: {:#}",
+                        pom_path.display(),
+                        error
+                    );
+                    continue;
+                }
+            };
+
+            pending.extend(Self::declared_modules(&module_dir, &pom));
+            modules.push(module_dir);
+        }
+
+        Ok(modules)
+    }
+
+    /// Find the outermost reactor which declares the module directory as one of its modules.
+    fn reactor_root(module_dir: &Path) -> PathBuf {
+        let mut reactor_dir = module_dir.to_owned();
+        for parent in module_dir.ancestors().skip(1).take(MAX_REACTOR_DEPTH) {
+            let Ok(pom) = fs::read_to_string(parent.join("pom.xml")) else {
+                continue;
+            };
+
+            if Self::declared_modules(parent, &pom).contains(&reactor_dir) {
+                reactor_dir = parent.to_owned();
+            }
+        }
+
+        reactor_dir
+    }
+
+    /// Identify the build outputs of all modules of the reactor.
+    /// The target directories of multiple modules are grouped into a single target.
+    fn identify_reactor(reactor_dir: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        let mut target_dirs = Vec::new();
+        for module_dir in Self::reactor_modules(reactor_dir)? {
+            target_dirs.extend(Self::module_target_dir(&module_dir));
+        }
+
+        let target: Box<dyn SweepableTarget> = match target_dirs.len() {
+            0 => return Ok(vec![]),
+            1 => Box::new(DirectoryTarget::named(
+                "maven-target",
+                target_dirs.remove(0),
+            )),
+            /* the group is identified by the reactor pom which itself is not part of the set */
+            _ => Box::new(FileSetTarget::new(
+                "maven-reactor",
+                reactor_dir.join("pom.xml"),
+                target_dirs,
+            )),
+        };
+        Ok(vec![target])
+    }
+}
+
+impl Sweeper for MavenSweeper {
+    fn name(&self) -> &str {
+        "maven"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+
+        if path.file_name_truncate() == "target" {
+            let Some(module_dir) = path.parent() else {
+                return Ok(vec![]);
+            };
+
+            if !module_dir.join("pom.xml").is_file() {
+                return Ok(vec![]);
+            }
+
+            /* reported as part of the reactor the module belongs to */
+            return Self::identify_reactor(&Self::reactor_root(module_dir));
+        }
+
+        if !path.join("pom.xml").is_file() || Self::reactor_root(path) != path {
+            /* modules are identified together with their reactor */
+            return Ok(vec![]);
+        }
+
+        Self::identify_reactor(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        path::Path,
+        process,
+    };
+
+    use super::{
+        parse_modules,
+        MavenSweeper,
+    };
+    use crate::Sweeper;
+
+    #[test]
+    fn parses_modules() {
+        let pom = r#"<project>
+  <modules>
+    <module>core</module>
+    <module> web/pom.xml </module>
+  </modules>
+  <profiles><profile><modules><module>extra</module></modules></profile></profiles>
+</project>"#;
+        assert_eq!(parse_modules(pom), ["core", "web/pom.xml", "extra"]);
+        assert!(parse_modules("<project><module>x</module></project>").is_empty());
+    }
+
+    fn create_module(dir: &Path, modules: &[&str]) {
+        let modules = modules
+            .iter()
+            .map(|module| format!("<module>{}</module>", module))
+            .collect::<String>();
+        fs::create_dir_all(dir.join("target").join("classes")).unwrap();
+        fs::write(
+            dir.join("pom.xml"),
+            format!("<project><modules>{}</modules></project>", modules),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn groups_reactor_modules() {
+        let root = env::temp_dir().join(format!("bclean-maven-{}", process::id()));
+        create_module(&root, &["core", "../outside"]);
+        create_module(&root.join("core"), &[]);
+        create_module(&root.join("outside"), &[]);
+        fs::write(root.join("core").join("target").join("CACHEDIR.TAG"), "").unwrap();
+        create_module(&root.join("web"), &[]);
+        /* outputs of plugins like war or dependency:copy-dependencies */
+        for dir in ["web-1.0", "lib", "quarkus-app"] {
+            fs::create_dir_all(root.join("web").join("target").join(dir)).unwrap();
+        }
+        let sweeper = MavenSweeper::new();
+
+        let reactor = sweeper.identify_targets(&root).unwrap();
+        let module = sweeper.identify_targets(&root.join("core")).unwrap();
+        fs::remove_file(root.join("core").join("target").join("CACHEDIR.TAG")).unwrap();
+        fs::write(
+            root.join("pom.xml"),
+            "<project><modules><module>core</module><module>web</module></modules></project>",
+        )
+        .unwrap();
+        let grouped = sweeper.identify_targets(&root).unwrap();
+        let grouped_from_module = sweeper
+            .identify_targets(&root.join("web").join("target"))
+            .unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        /* the core target belongs to cargo */
+        assert_eq!(reactor.len(), 1);
+        assert_eq!(reactor[0].path(), root.join("target"));
+        assert!(module.is_empty());
+
+        assert_eq!(grouped.len(), 1);
+        assert_eq!(grouped[0].name(), "maven-reactor");
+        assert_eq!(grouped[0].path(), root.join("pom.xml"));
+        assert_eq!(grouped[0].files().len(), 3);
+        assert_eq!(grouped_from_module[0].path(), root.join("pom.xml"));
+    }
+}
@@ -7,7 +7,10 @@ use std::{
 
 use thiserror::Error;
 
-use crate::target::SweepableTarget;
+use crate::target::{
+    FileSetError,
+    SweepableTarget,
+};
 
 /// A Sweeper implements a cleanup mechanism for a specific language or build artefact.
 pub trait Sweeper: Sync + Send {
@@ -15,10 +18,25 @@ pub trait Sweeper: Sync + Send {
     fn name(&self) -> &str;
 
     /// Find all possible targets for the sweeper based on the target options.
+    /// The targets do not need to be located at the directory itself,
+    /// e.g. a project root may identify the build outputs of all its modules.
     fn identify_targets(
         &self,
         directory: &Path,
     ) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError>;
+
+    /// Find targets which do not belong to any project, e.g. global caches of a toolchain.
+    /// These targets will be reported once per scan regardless of the root directory.
+    fn identify_global_targets(&self) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        Ok(vec![])
+    }
+
+    /// Called after every completed scan.
+    /// Sweepers collecting information across all inspected directories
+    /// may report the resulting targets here.
+    fn finish_scan(&self) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        Ok(vec![])
+    }
 }
 
 #[derive(Error, Debug)]
@@ -26,6 +44,9 @@ pub enum SweeperError {
     #[error("io: {0}")]
     IoError(#[from] io::Error),
 
+    #[error("{0}")]
+    FileSet(#[from] FileSetError),
+
     #[error("{0}")]
     Other(#[from] Box<dyn error::Error + Send>),
 }
@@ -38,3 +59,68 @@ pub use node::*;
 
 mod cmake;
 pub use cmake::*;
+
+mod python;
+pub use python::*;
+
+mod gradle;
+pub use gradle::*;
+
+mod maven;
+pub use maven::*;
+
+mod dotnet;
+pub use dotnet::*;
+
+mod go;
+pub use go::*;
+
+mod haskell;
+pub use haskell::*;
+
+mod elixir;
+pub use elixir::*;
+
+mod marker;
+
+mod zig;
+pub use zig::*;
+
+mod swift;
+pub use swift::*;
+
+mod dart;
+pub use dart::*;
+
+mod frontend;
+pub use frontend::*;
+
+mod monorepo;
+pub use monorepo::*;
+
+mod global_cache;
+pub use global_cache::*;
+
+mod rustup;
+pub use rustup::*;
+
+mod compiler_cache;
+pub use compiler_cache::*;
+
+mod game_engine;
+pub use game_engine::*;
+
+mod terraform;
+pub use terraform::*;
+
+mod docs;
+pub use docs::*;
+
+mod latex;
+pub use latex::*;
+
+mod core_dump;
+pub use core_dump::*;
+
+mod custom;
+pub use custom::*;
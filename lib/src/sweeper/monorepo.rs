@@ -0,0 +1,247 @@
+use std::{
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    fs::{
+        estimate_size_async,
+        remove_dir_all_writable,
+    },
+    path::PathEx,
+    target::{
+        CleanupResult,
+        DirectoryTarget,
+        SizeEstimator,
+    },
+};
+
+const BAZEL_WORKSPACE_FILES: [&str; 4] =
+    ["WORKSPACE", "WORKSPACE.bazel", "MODULE.bazel", "REPO.bazel"];
+
+/// Prefix of the convenience symlinks bazel creates within the workspace
+const BAZEL_SYMLINK_PREFIX: &str = "bazel-";
+
+/// Returns the output base the convenience symlink points into.
+/// All symlinks point into `<output base>/execroot/<workspace>`.
+fn resolve_output_base(symlink: &Path) -> Option<PathBuf> {
+    let link_target = fs::read_link(symlink).ok()?;
+    let link_target = match symlink.parent() {
+        Some(parent) => parent.join(link_target),
+        None => link_target,
+    };
+
+    link_target
+        .ancestors()
+        .find(|path| path.file_name_truncate() == "execroot")
+        .and_then(|execroot| execroot.parent())
+        .filter(|output_base| output_base.is_dir())
+        .map(Path::to_owned)
+}
+
+/// The output base of a bazel workspace including the convenience symlinks pointing into it.
+/// The output base is located outside of the workspace, e.g. within `~/.cache/bazel`.
+#[derive(Debug)]
+struct BazelOutputTarget {
+    output_base: PathBuf,
+    symlinks: Vec<PathBuf>,
+}
+
+impl SweepableTarget for BazelOutputTarget {
+    fn name(&self) -> &str {
+        "bazel-output-base"
+    }
+
+    fn path(&self) -> &Path {
+        &self.output_base
+    }
+
+    /// The output base is located outside of the workspace.
+    fn is_global(&self) -> bool {
+        true
+    }
+
+    fn estimated_size(&self) -> Box<SizeEstimator> {
+        Box::new(estimate_size_async(self.output_base.clone()))
+    }
+
+    fn cleanup(&mut self, dry_run: bool) -> Result<CleanupResult, SweeperError> {
+        let size_total = self.estimated_size().last();
+        let result = CleanupResult {
+            bytes_erased: size_total,
+        };
+        if dry_run {
+            return Ok(result);
+        }
+
+        /* bazel marks its outputs and external repositories read-only */
+        remove_dir_all_writable(&self.output_base)?;
+        for symlink in &self.symlinks {
+            fs::remove_file(symlink)?;
+        }
+        Ok(result)
+    }
+}
+
+#[derive(Default)]
+pub struct MonorepoSweeper;
+
+impl MonorepoSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn is_bazel_workspace(path: &Path) -> bool {
+        BAZEL_WORKSPACE_FILES
+            .iter()
+            .any(|file_name| path.join(file_name).is_file())
+    }
+
+    /// Group the convenience symlinks of the workspace by the output base they point into.
+    fn identify_bazel_outputs(
+        workspace_dir: &Path,
+    ) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        let mut targets: Vec<BazelOutputTarget> = Vec::new();
+        for entry in workspace_dir.read_dir()? {
+            let entry = entry?;
+            if !entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(BAZEL_SYMLINK_PREFIX)
+                || !entry.file_type()?.is_symlink()
+            {
+                continue;
+            }
+
+            let symlink = entry.path();
+            let Some(output_base) = resolve_output_base(&symlink) else {
+                continue;
+            };
+
+            match targets
+                .iter_mut()
+                .find(|target| target.output_base == output_base)
+            {
+                Some(target) => target.symlinks.push(symlink),
+                None => targets.push(BazelOutputTarget {
+                    output_base,
+                    symlinks: vec![symlink],
+                }),
+            }
+        }
+
+        Ok(targets
+            .into_iter()
+            .map(|target| Box::new(target) as Box<dyn SweepableTarget>)
+            .collect())
+    }
+}
+
+impl Sweeper for MonorepoSweeper {
+    fn name(&self) -> &str {
+        "monorepo"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !path.is_dir() || path.is_symlink() {
+            return Ok(vec![]);
+        }
+
+        if Self::is_bazel_workspace(path) {
+            return Self::identify_bazel_outputs(path);
+        }
+
+        let Some(parent) = path.parent() else {
+            return Ok(vec![]);
+        };
+
+        let target_name = match path.file_name_truncate() {
+            "buck-out" if parent.join(".buckconfig").is_file() => "buck-out",
+            ".pants.d" if parent.join("pants.toml").is_file() => "pants-workdir",
+            _ => return Ok(vec![]),
+        };
+
+        Ok(vec![Box::new(DirectoryTarget::named(
+            target_name,
+            path.to_owned(),
+        ))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::MonorepoSweeper;
+    use crate::Sweeper;
+
+    #[test]
+    fn identifies_buck_and_pants_outputs() {
+        let root = env::temp_dir().join(format!("bclean-monorepo-{}", process::id()));
+        fs::create_dir_all(root.join("buck").join("buck-out")).unwrap();
+        fs::write(root.join("buck").join(".buckconfig"), "").unwrap();
+        fs::create_dir_all(root.join("pants").join(".pants.d")).unwrap();
+        fs::write(root.join("pants").join("pants.toml"), "").unwrap();
+        fs::create_dir_all(root.join("plain").join("buck-out")).unwrap();
+        fs::create_dir_all(root.join("plain").join(".pants.d")).unwrap();
+
+        let sweeper = MonorepoSweeper::new();
+        let identify = |path: &str| sweeper.identify_targets(&root.join(path)).unwrap();
+        let buck = identify("buck/buck-out");
+        let pants = identify("pants/.pants.d");
+        let plain = [identify("plain/buck-out"), identify("plain/.pants.d")];
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(buck.len(), 1);
+        assert_eq!(buck[0].name(), "buck-out");
+        assert_eq!(pants.len(), 1);
+        assert_eq!(pants[0].name(), "pants-workdir");
+        assert!(plain.iter().all(Vec::is_empty));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn groups_bazel_symlinks_by_output_base() {
+        use std::os::unix::fs::symlink;
+
+        let root = env::temp_dir().join(format!("bclean-bazel-{}", process::id()));
+        let output_base = root.join("cache").join("0123abcd");
+        let execroot = output_base.join("execroot").join("_main");
+        fs::create_dir_all(execroot.join("bazel-out")).unwrap();
+
+        let workspace = root.join("workspace");
+        fs::create_dir_all(&workspace).unwrap();
+        fs::write(workspace.join("MODULE.bazel"), "").unwrap();
+        symlink(&execroot, workspace.join("bazel-workspace")).unwrap();
+        symlink(execroot.join("bazel-out"), workspace.join("bazel-out")).unwrap();
+        /* symlinks which do not point into an output base are not touched */
+        symlink(&workspace, workspace.join("bazel-elsewhere")).unwrap();
+
+        let not_workspace = root.join("not-workspace");
+        fs::create_dir_all(&not_workspace).unwrap();
+        symlink(&execroot, not_workspace.join("bazel-out")).unwrap();
+
+        let sweeper = MonorepoSweeper::new();
+        let targets = sweeper.identify_targets(&workspace).unwrap();
+        let not_workspace = sweeper.identify_targets(&not_workspace).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].name(), "bazel-output-base");
+        assert_eq!(targets[0].path(), output_base);
+        assert!(targets[0].is_global());
+        assert!(not_workspace.is_empty());
+    }
+}
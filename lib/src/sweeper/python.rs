@@ -0,0 +1,214 @@
+use std::{
+    fs,
+    path::Path,
+};
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::PathEx,
+    target::DirectoryTarget,
+};
+
+/// Files marking the root of a python project
+const PROJECT_MARKERS: [&str; 3] = ["pyproject.toml", "setup.py", "setup.cfg"];
+
+/// Tool caches which are tagged with a `CACHEDIR.TAG`
+const TOOL_CACHES: [(&str, &str); 3] = [
+    (".mypy_cache", "py-mypy-cache"),
+    (".pytest_cache", "py-pytest-cache"),
+    (".ruff_cache", "py-ruff-cache"),
+];
+
+#[derive(Default)]
+pub struct PythonSweeper;
+
+impl PythonSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn is_project_dir(path: &Path) -> bool {
+        PROJECT_MARKERS
+            .iter()
+            .any(|marker| path.join(marker).is_file())
+    }
+
+    fn contains_bytecode(path: &Path) -> Result<bool, SweeperError> {
+        let contains_bytecode = path
+            .read_dir()?
+            .filter_map(|entry| entry.ok())
+            .any(|entry| entry.file_name().to_string_lossy().ends_with(".pyc"));
+
+        Ok(contains_bytecode)
+    }
+
+    /// Returns `true` if the interpreter the virtual environment has been created from still exists.
+    fn venv_interpreter_exists(config: &str) -> bool {
+        for line in config.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+
+            match key.trim() {
+                "home" => return Path::new(value.trim()).is_dir(),
+                "executable" => return Path::new(value.trim()).is_file(),
+                _ => {}
+            }
+        }
+
+        /* the interpreter is unknown, assume it exists */
+        true
+    }
+
+    fn identify_venv(path: &Path) -> Result<Option<Box<dyn SweepableTarget>>, SweeperError> {
+        let config_path = path.join("pyvenv.cfg");
+        if !config_path.is_file() {
+            return Ok(None);
+        }
+
+        let config = fs::read_to_string(&config_path)?;
+        let name = if Self::venv_interpreter_exists(&config) {
+            "py-venv"
+        } else {
+            "py-venv-broken"
+        };
+
+        Ok(Some(Box::new(DirectoryTarget::named(
+            name,
+            path.to_owned(),
+        ))))
+    }
+}
+
+impl Sweeper for PythonSweeper {
+    fn name(&self) -> &str {
+        "python"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+
+        if let Some(target) = Self::identify_venv(path)? {
+            return Ok(vec![target]);
+        }
+
+        let parent = match path.parent() {
+            Some(parent) => parent,
+            None => return Ok(vec![]),
+        };
+
+        let file_name = path.file_name_truncate();
+        let target_name = match file_name {
+            "__pycache__" if Self::contains_bytecode(path)? => "py-pycache",
+            ".tox" if parent.join("tox.ini").is_file() || Self::is_project_dir(parent) => "py-tox",
+            ".nox" if parent.join("noxfile.py").is_file() => "py-nox",
+            "build" if Self::is_project_dir(parent) => "py-build",
+            "dist" if Self::is_project_dir(parent) => "py-dist",
+            name if name.ends_with(".egg-info") && Self::is_project_dir(parent) => "py-egg-info",
+            name => match TOOL_CACHES.iter().find(|(dir_name, _)| *dir_name == name) {
+                Some((_, target_name)) if path.join("CACHEDIR.TAG").is_file() => target_name,
+                _ => return Ok(vec![]),
+            },
+        };
+
+        Ok(vec![Box::new(DirectoryTarget::named(
+            target_name,
+            path.to_owned(),
+        ))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::PythonSweeper;
+    use crate::Sweeper;
+
+    #[test]
+    fn identifies_python_targets() {
+        let root = env::temp_dir().join(format!("bclean-python-{}", process::id()));
+        let project = root.join("project");
+        for dir in [
+            "__pycache__",
+            ".tox",
+            "build",
+            "dist",
+            "demo.egg-info",
+            ".mypy_cache",
+        ] {
+            fs::create_dir_all(project.join(dir)).unwrap();
+        }
+        fs::write(project.join("pyproject.toml"), "").unwrap();
+        fs::write(project.join("__pycache__").join("main.cpython-312.pyc"), "").unwrap();
+        fs::write(project.join(".mypy_cache").join("CACHEDIR.TAG"), "").unwrap();
+
+        /* directories without project markers, bytecode or cache tags */
+        let plain = root.join("plain");
+        for dir in ["__pycache__", ".tox", "build", "dist", ".mypy_cache"] {
+            fs::create_dir_all(plain.join(dir)).unwrap();
+        }
+
+        fs::create_dir_all(root.join("venv")).unwrap();
+        fs::write(
+            root.join("venv").join("pyvenv.cfg"),
+            format!("home = {}\n", root.display()),
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("broken-venv")).unwrap();
+        fs::write(
+            root.join("broken-venv").join("pyvenv.cfg"),
+            format!("home = {}\n", root.join("missing").display()),
+        )
+        .unwrap();
+
+        let sweeper = PythonSweeper::new();
+        let identify = |path: &str| {
+            sweeper
+                .identify_targets(&root.join(path))
+                .unwrap()
+                .iter()
+                .map(|target| target.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        let targets = [
+            "project/__pycache__",
+            "project/.tox",
+            "project/build",
+            "project/dist",
+            "project/demo.egg-info",
+            "project/.mypy_cache",
+            "venv",
+            "broken-venv",
+        ]
+        .map(identify);
+        let plain = ["__pycache__", ".tox", "build", "dist", ".mypy_cache"]
+            .map(|dir| identify(&format!("plain/{}", dir)));
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            targets,
+            [
+                ["py-pycache"],
+                ["py-tox"],
+                ["py-build"],
+                ["py-dist"],
+                ["py-egg-info"],
+                ["py-mypy-cache"],
+                ["py-venv"],
+                ["py-venv-broken"],
+            ]
+        );
+        assert!(plain.iter().all(Vec::is_empty));
+    }
+}
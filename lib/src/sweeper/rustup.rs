@@ -0,0 +1,396 @@
+use std::{
+    collections::HashSet,
+    fs,
+    io,
+    mem,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Mutex,
+};
+
+use serde_json::Value;
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    dirs::{
+        env_path,
+        home_dir,
+    },
+    path::PathEx,
+    target::DirectoryTarget,
+};
+
+const TOOLCHAIN_FILES: [&str; 2] = ["rust-toolchain", "rust-toolchain.toml"];
+
+/// Returns the value of a `key = "value"` line.
+fn parse_toml_string<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let (line_key, value) = line.split_once('=')?;
+    if line_key.trim().trim_matches('"') != key {
+        return None;
+    }
+
+    Some(parse_toml_value(value))
+}
+
+/// Returns the content of a quoted value or the value without a trailing comment.
+fn parse_toml_value(value: &str) -> &str {
+    let value = value.trim();
+    match value.chars().next() {
+        Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or_default(),
+        _ => value.split('#').next().unwrap_or_default().trim(),
+    }
+}
+
+/// Extract the toolchain channel of a `rust-toolchain` or `rust-toolchain.toml` file.
+fn parse_toolchain_file(content: &str) -> Option<String> {
+    let content = content.trim();
+
+    /* the legacy format only contains the channel */
+    if !content.contains('=') {
+        return content.lines().next().map(str::to_string);
+    }
+
+    content
+        .lines()
+        .find_map(|line| parse_toml_string(line, "channel"))
+        .map(str::to_string)
+}
+
+/// Extract the commit hashes of all rustc versions cargo used for the target directory.
+fn parse_rustc_info(content: &str) -> Result<Vec<String>, SweeperError> {
+    let rustc_info: Value = serde_json::from_str(content)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+    let Some(outputs) = rustc_info.get("outputs").and_then(Value::as_object) else {
+        return Ok(vec![]);
+    };
+
+    let commit_hashes = outputs
+        .values()
+        .filter_map(|output| output.get("stdout")?.as_str())
+        .flat_map(|stdout| stdout.lines())
+        .filter_map(|line| line.strip_prefix("commit-hash:"))
+        .map(|commit_hash| commit_hash.trim().to_string())
+        .collect();
+    Ok(commit_hashes)
+}
+
+/// Extract the default toolchain and the toolchains of all directory overrides of the rustup settings.
+fn parse_settings(settings: &str) -> Vec<String> {
+    let mut toolchains = Vec::new();
+    let mut in_overrides = false;
+    for line in settings.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_overrides = line == "[overrides]";
+            continue;
+        }
+
+        if in_overrides {
+            /* the directory is quoted and may contain any character */
+            if let Some((_, toolchain)) = line.rsplit_once('=') {
+                toolchains.push(parse_toml_value(toolchain).to_string());
+            }
+        } else if let Some(toolchain) = parse_toml_string(line, "default_toolchain") {
+            toolchains.push(toolchain.to_string());
+        }
+    }
+
+    toolchains
+}
+
+/// Extract the abbreviated rustc commit hash of a `multirust-channel-manifest.toml`.
+fn parse_manifest_commit_hash(manifest: &str) -> Option<String> {
+    /* the rustc version looks like: 1.75.0 (82e1608df 2023-12-21) */
+    let (_, rustc_section) = manifest.split_once("[pkg.rustc]")?;
+    let version = rustc_section
+        .lines()
+        .take_while(|line| !line.starts_with('['))
+        .find_map(|line| parse_toml_string(line, "version"))?;
+    let (_, commit) = version.split_once('(')?;
+    commit.split_whitespace().next().map(str::to_string)
+}
+
+/// Toolchains referenced by the projects of the current scan
+#[derive(Debug, Default)]
+struct ToolchainUsage {
+    channels: HashSet<String>,
+    commit_hashes: HashSet<String>,
+}
+
+/// Reports installed rustup toolchains which are not used by any scanned project.
+#[derive(Default)]
+pub struct RustupSweeper {
+    usage: Mutex<ToolchainUsage>,
+}
+
+impl RustupSweeper {
+    pub fn new() -> Self {
+        Self {
+            usage: Default::default(),
+        }
+    }
+
+    fn rustup_home() -> Option<PathBuf> {
+        env_path("RUSTUP_HOME").or_else(|| home_dir().map(|home| home.join(".rustup")))
+    }
+
+    /// Returns the default toolchain and all directory overrides configured for rustup.
+    fn configured_toolchains(rustup_home: &Path) -> Result<Vec<String>, SweeperError> {
+        match fs::read_to_string(rustup_home.join("settings.toml")) {
+            Ok(settings) => Ok(parse_settings(&settings)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Returns the abbreviated commit hash of the rustc shipped with the toolchain.
+    fn toolchain_commit_hash(toolchain_dir: &Path) -> Option<String> {
+        let manifest = fs::read_to_string(
+            toolchain_dir
+                .join("lib")
+                .join("rustlib")
+                .join("multirust-channel-manifest.toml"),
+        )
+        .ok()?;
+
+        parse_manifest_commit_hash(&manifest)
+    }
+
+    /// Test if the toolchain directory name matches the channel,
+    /// e.g. the channel `nightly-2024-01-01` matches `nightly-2024-01-01-x86_64-unknown-linux-gnu`
+    /// and the channel `1.75` matches `1.75.0-x86_64-unknown-linux-gnu`.
+    fn matches_channel(toolchain: &str, channel: &str) -> bool {
+        let Some(rest) = toolchain.strip_prefix(channel) else {
+            return false;
+        };
+
+        /* a version without the patch version refers to the latest patch release */
+        let rest = match rest.strip_prefix('.') {
+            Some(patch) => patch.trim_start_matches(|c: char| c.is_ascii_digit()),
+            None => rest,
+        };
+        rest.is_empty() || rest.starts_with('-')
+    }
+
+    fn is_toolchain_used(
+        toolchain_dir: &Path,
+        configured: &[String],
+        usage: Option<&ToolchainUsage>,
+    ) -> bool {
+        let toolchain = toolchain_dir.file_name_truncate();
+        if configured
+            .iter()
+            .any(|channel| Self::matches_channel(toolchain, channel))
+        {
+            return true;
+        }
+
+        let Some(usage) = usage else {
+            /* without usage information every toolchain has to be considered used */
+            return true;
+        };
+
+        if usage
+            .channels
+            .iter()
+            .any(|channel| Self::matches_channel(toolchain, channel))
+        {
+            return true;
+        }
+
+        match Self::toolchain_commit_hash(toolchain_dir) {
+            Some(short_hash) => usage
+                .commit_hashes
+                .iter()
+                .any(|commit_hash| commit_hash.starts_with(&short_hash)),
+            /* the version of the toolchain is unknown, keep it */
+            None => true,
+        }
+    }
+}
+
+impl Sweeper for RustupSweeper {
+    fn name(&self) -> &str {
+        "rustup"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        /* cargo target directories are not entered, therefore check the directory itself */
+        let rustc_info = path.join(".rustc_info.json");
+        if rustc_info.is_file() {
+            let commit_hashes = parse_rustc_info(&fs::read_to_string(rustc_info)?)?;
+            if let Ok(mut usage) = self.usage.lock() {
+                usage.commit_hashes.extend(commit_hashes);
+            }
+        } else if TOOLCHAIN_FILES.contains(&path.file_name_truncate()) && path.is_file() {
+            if let Some(channel) = parse_toolchain_file(&fs::read_to_string(path)?) {
+                if let Ok(mut usage) = self.usage.lock() {
+                    usage.channels.insert(channel);
+                }
+            }
+        }
+
+        Ok(vec![])
+    }
+
+    fn finish_scan(&self) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        /* the usage only applies to the projects of the finished scan */
+        let usage = self
+            .usage
+            .lock()
+            .map(|mut usage| mem::take(&mut *usage))
+            .ok();
+
+        let Some(rustup_home) = Self::rustup_home() else {
+            return Ok(vec![]);
+        };
+
+        let toolchains_dir = rustup_home.join("toolchains");
+        if !toolchains_dir.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let configured = Self::configured_toolchains(&rustup_home)?;
+
+        let mut targets: Vec<Box<dyn SweepableTarget>> = Vec::new();
+        for entry in toolchains_dir.read_dir()? {
+            let entry = entry?;
+
+            /* linked toolchains are not managed by rustup */
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+
+            let toolchain_dir = entry.path();
+            if Self::is_toolchain_used(&toolchain_dir, &configured, usage.as_ref()) {
+                continue;
+            }
+
+            targets.push(Box::new(DirectoryTarget::named(
+                "rustup-toolchain",
+                toolchain_dir,
+            )));
+        }
+
+        Ok(targets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parse_manifest_commit_hash,
+        parse_rustc_info,
+        parse_settings,
+        parse_toolchain_file,
+        RustupSweeper,
+    };
+
+    #[test]
+    fn matches_channels() {
+        let toolchain = "1.75.0-x86_64-unknown-linux-gnu";
+        assert!(RustupSweeper::matches_channel(toolchain, "1.75.0"));
+        assert!(RustupSweeper::matches_channel(toolchain, "1.75"));
+        assert!(RustupSweeper::matches_channel(toolchain, toolchain));
+        assert!(!RustupSweeper::matches_channel(toolchain, "1.7"));
+        assert!(!RustupSweeper::matches_channel(toolchain, "1.75.1"));
+
+        let toolchain = "nightly-2024-01-01-x86_64-unknown-linux-gnu";
+        assert!(RustupSweeper::matches_channel(
+            toolchain,
+            "nightly-2024-01-01"
+        ));
+        assert!(!RustupSweeper::matches_channel(
+            toolchain,
+            "nightly-2024-01-0"
+        ));
+        assert!(!RustupSweeper::matches_channel(toolchain, "stable"));
+    }
+
+    #[test]
+    fn parses_legacy_toolchain_files() {
+        assert_eq!(
+            parse_toolchain_file("nightly-2024-01-01\n"),
+            Some("nightly-2024-01-01".into())
+        );
+        assert_eq!(parse_toolchain_file("  1.75.0  "), Some("1.75.0".into()));
+        assert_eq!(parse_toolchain_file(""), None);
+    }
+
+    #[test]
+    fn parses_toml_toolchain_files() {
+        let toolchain = "[toolchain]\nchannel = \"1.75.0\"\ncomponents = [\"rustfmt\"]\n";
+        assert_eq!(parse_toolchain_file(toolchain), Some("1.75.0".into()));
+
+        let toolchain = "[toolchain]\nchannel = 'nightly' # pinned for the ci\n";
+        assert_eq!(parse_toolchain_file(toolchain), Some("nightly".into()));
+
+        let toolchain = "[toolchain]\npath = \"/opt/rust\"\n";
+        assert_eq!(parse_toolchain_file(toolchain), None);
+    }
+
+    #[test]
+    fn parses_rustc_info() {
+        let rustc_info = r#"{
+            "rustc_fingerprint": 1234,
+            "outputs": {
+                "1": {"success": true, "status": "", "code": 0, "stdout": "rustc 1.75.0 (82e1608df 2023-12-21)\nbinary: rustc\ncommit-hash: 82e1608dfa6e0b5569232559e3d385fea5a93112\nhost: x86_64-unknown-linux-gnu\n", "stderr": ""},
+                "2": {"success": true, "status": "", "code": 0, "stdout": "___\n", "stderr": ""}
+            }
+        }"#;
+        assert_eq!(
+            parse_rustc_info(rustc_info).unwrap(),
+            ["82e1608dfa6e0b5569232559e3d385fea5a93112"]
+        );
+        assert!(parse_rustc_info("{}").unwrap().is_empty());
+        assert!(parse_rustc_info("not json").is_err());
+    }
+
+    #[test]
+    fn parses_settings() {
+        let settings = r#"default_host_triple = "x86_64-unknown-linux-gnu"
+default_toolchain = "stable-x86_64-unknown-linux-gnu"
+profile = "default"
+version = "12"
+
+[overrides]
+"/home/user/legacy" = "1.60.0-x86_64-unknown-linux-gnu"
+"/home/user/a=b" = "nightly-2024-01-01"
+"#;
+        assert_eq!(
+            parse_settings(settings),
+            [
+                "stable-x86_64-unknown-linux-gnu",
+                "1.60.0-x86_64-unknown-linux-gnu",
+                "nightly-2024-01-01"
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_manifest_commit_hash() {
+        let manifest = r#"date = "2023-12-28"
+manifest-version = "2"
+
+[pkg.cargo]
+version = "1.75.0 (1d8b05cdd 2023-11-20)"
+
+[pkg.rustc]
+version = "1.75.0 (82e1608df 2023-12-21)"
+"#;
+        assert_eq!(
+            parse_manifest_commit_hash(manifest),
+            Some("82e1608df".into())
+        );
+        assert_eq!(
+            parse_manifest_commit_hash("[pkg.cargo]\nversion = \"1.75.0\"\n"),
+            None
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use std::path::Path;
+
+use super::{
+    marker::identify_marked_dir,
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+
+/// Output directories of the swift package manager next to the `Package.swift`
+const TARGET_DIRS: [(&str, &str); 1] = [(".build", "swift-build")];
+
+#[derive(Default)]
+pub struct SwiftSweeper;
+
+impl SwiftSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Sweeper for SwiftSweeper {
+    fn name(&self) -> &str {
+        "swift"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        identify_marked_dir(path, "Package.swift", &TARGET_DIRS)
+    }
+}
@@ -0,0 +1,143 @@
+use std::path::Path;
+
+use super::{
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+use crate::{
+    path::PathEx,
+    target::DirectoryTarget,
+};
+
+/// Subdirectories of `.terraform` which are restored by `terraform init`.
+/// The directory itself is not a target as it contains the backend state `terraform.tfstate`.
+const TERRAFORM_CACHES: [(&str, &str); 3] = [
+    ("providers", "terraform-providers"),
+    /* providers of terraform versions before 0.14 */
+    ("plugins", "terraform-providers"),
+    ("modules", "terraform-modules"),
+];
+
+#[derive(Default)]
+pub struct TerraformSweeper;
+
+impl TerraformSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn is_terraform_module(path: &Path) -> Result<bool, SweeperError> {
+        let contains_configuration = path
+            .read_dir()?
+            .filter_map(|entry| entry.ok())
+            .any(|entry| {
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                (file_name.ends_with(".tf") || file_name.ends_with(".tf.json"))
+                    && entry.path().is_file()
+            });
+
+        Ok(contains_configuration)
+    }
+}
+
+impl Sweeper for TerraformSweeper {
+    fn name(&self) -> &str {
+        "terraform"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let Some(parent) = path.parent() else {
+            return Ok(vec![]);
+        };
+
+        match path.file_name_truncate() {
+            ".terraform" => {
+                if !Self::is_terraform_module(parent)? {
+                    return Ok(vec![]);
+                }
+
+                let targets = TERRAFORM_CACHES
+                    .iter()
+                    .map(|(dir_name, target_name)| (path.join(dir_name), *target_name))
+                    .filter(|(cache_dir, _)| cache_dir.is_dir() && !cache_dir.is_symlink())
+                    .map(|(cache_dir, target_name)| {
+                        Box::new(DirectoryTarget::named(target_name, cache_dir))
+                            as Box<dyn SweepableTarget>
+                    })
+                    .collect();
+                Ok(targets)
+            }
+            ".terragrunt-cache" if parent.join("terragrunt.hcl").is_file() => Ok(vec![Box::new(
+                DirectoryTarget::named("terragrunt-cache", path.to_owned()),
+            )]),
+            _ => Ok(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::TerraformSweeper;
+    use crate::Sweeper;
+
+    #[test]
+    fn identifies_caches_within_dot_terraform() {
+        let root = env::temp_dir().join(format!("bclean-terraform-{}", process::id()));
+        let module = root.join("module");
+        fs::create_dir_all(module.join(".terraform").join("providers")).unwrap();
+        fs::create_dir_all(module.join(".terraform").join("modules")).unwrap();
+        fs::write(module.join(".terraform").join("terraform.tfstate"), "{}").unwrap();
+        fs::write(module.join("main.tf"), "").unwrap();
+
+        let terragrunt = root.join("terragrunt");
+        fs::create_dir_all(terragrunt.join(".terragrunt-cache")).unwrap();
+        fs::write(terragrunt.join("terragrunt.hcl"), "").unwrap();
+
+        let plain = root.join("plain");
+        fs::create_dir_all(plain.join(".terraform").join("providers")).unwrap();
+        fs::create_dir_all(plain.join(".terragrunt-cache")).unwrap();
+
+        let sweeper = TerraformSweeper::new();
+        let identify = |path: &str| sweeper.identify_targets(&root.join(path)).unwrap();
+        let module_targets = identify("module/.terraform")
+            .iter()
+            .map(|target| (target.name().to_string(), target.path().to_owned()))
+            .collect::<Vec<_>>();
+        let terragrunt = identify("terragrunt/.terragrunt-cache");
+        let ignored = [
+            identify("module/.terraform/providers"),
+            identify("plain/.terraform"),
+            identify("plain/.terragrunt-cache"),
+        ];
+        fs::remove_dir_all(&root).unwrap();
+
+        /* the backend state within .terraform is never part of a target */
+        assert_eq!(
+            module_targets,
+            [
+                (
+                    "terraform-providers".to_string(),
+                    module.join(".terraform").join("providers")
+                ),
+                (
+                    "terraform-modules".to_string(),
+                    module.join(".terraform").join("modules")
+                ),
+            ]
+        );
+        assert_eq!(terragrunt.len(), 1);
+        assert_eq!(terragrunt[0].name(), "terragrunt-cache");
+        assert!(ignored.iter().all(Vec::is_empty));
+    }
+}
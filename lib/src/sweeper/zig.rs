@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use super::{
+    marker::identify_marked_dir,
+    SweepableTarget,
+    Sweeper,
+    SweeperError,
+};
+
+/// Output directories of `zig build` next to the `build.zig`
+const TARGET_DIRS: [(&str, &str); 3] = [
+    ("zig-cache", "zig-cache"),
+    (".zig-cache", "zig-cache"),
+    ("zig-out", "zig-out"),
+];
+
+#[derive(Default)]
+pub struct ZigSweeper;
+
+impl ZigSweeper {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Sweeper for ZigSweeper {
+    fn name(&self) -> &str {
+        "zig"
+    }
+
+    fn identify_targets(&self, path: &Path) -> Result<Vec<Box<dyn SweepableTarget>>, SweeperError> {
+        identify_marked_dir(path, "build.zig", &TARGET_DIRS)
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use super::{
+    CleanupResult,
+    SizeEstimator,
+    SweepableTarget,
+};
+use crate::{
+    fs,
+    SweeperError,
+};
+
+/// A global cache directory of a toolchain.
+/// Toolchains may protect their caches by making the cached entries read-only,
+/// these permissions will be lifted before the directory is removed.
+#[derive(Debug)]
+pub struct CacheDirectoryTarget {
+    name: String,
+    cache_dir: PathBuf,
+}
+
+impl CacheDirectoryTarget {
+    pub fn new(name: impl Into<String>, cache_dir: PathBuf) -> Self {
+        Self {
+            name: name.into(),
+            cache_dir,
+        }
+    }
+}
+
+impl SweepableTarget for CacheDirectoryTarget {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn path(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    fn is_global(&self) -> bool {
+        true
+    }
+
+    fn estimated_size(&self) -> Box<SizeEstimator> {
+        Box::new(fs::estimate_size_async(self.cache_dir.clone()))
+    }
+
+    fn cleanup(&mut self, dry_run: bool) -> Result<CleanupResult, SweeperError> {
+        let size_total = self.estimated_size().last();
+        let result = CleanupResult {
+            bytes_erased: size_total,
+        };
+        if dry_run {
+            return Ok(result);
+        }
+
+        fs::remove_dir_all_writable(&self.cache_dir)?;
+        Ok(result)
+    }
+}
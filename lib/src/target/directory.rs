@@ -0,0 +1,73 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use super::{
+    CleanupResult,
+    SizeEstimator,
+    SweepableTarget,
+};
+use crate::{
+    fs,
+    SweeperError,
+};
+
+#[derive(Debug)]
+pub struct DirectoryTarget {
+    name: String,
+    target_dir: PathBuf,
+    risky: bool,
+}
+
+impl DirectoryTarget {
+    pub fn new(target: PathBuf) -> Self {
+        Self::named("directory", target)
+    }
+
+    /// Create a directory target with a name describing the kind of the target.
+    pub fn named(name: impl Into<String>, target: PathBuf) -> Self {
+        Self {
+            name: name.into(),
+            target_dir: target,
+            risky: false,
+        }
+    }
+
+    /// Mark the directory as risky to remove, e.g. because it contains user configuration.
+    pub fn risky(mut self) -> Self {
+        self.risky = true;
+        self
+    }
+}
+
+impl SweepableTarget for DirectoryTarget {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn path(&self) -> &Path {
+        &self.target_dir
+    }
+
+    fn is_risky(&self) -> bool {
+        self.risky
+    }
+
+    fn estimated_size(&self) -> Box<SizeEstimator> {
+        Box::new(fs::estimate_size_async(self.target_dir.clone()))
+    }
+
+    fn cleanup(&mut self, dry_run: bool) -> Result<CleanupResult, SweeperError> {
+        let size_total = self.estimated_size().last();
+        let result = CleanupResult {
+            bytes_erased: size_total,
+        };
+        if dry_run {
+            return Ok(result);
+        }
+
+        std::fs::remove_dir_all(&self.target_dir)?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    error,
+    fmt,
+    fs,
+    io,
+    iter,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use super::{
+    CleanupResult,
+    SizeEstimator,
+    SweepableTarget,
+};
+use crate::{
+    fs::estimate_size_async,
+    SweeperError,
+};
+
+/// Suffix of files which have been staged for removal
+const STAGED_SUFFIX: &str = ".bclean-removing";
+
+/// Files of a file set which could not be removed
+#[derive(Debug)]
+pub struct FileSetError {
+    /// `true` if no file has been removed as the set could not be staged for removal
+    pub rolled_back: bool,
+    pub failures: Vec<(PathBuf, io::Error)>,
+}
+
+impl fmt::Display for FileSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.rolled_back {
+            write!(f, "nothing removed, ")?;
+        } else {
+            write!(f, "partially removed, ")?;
+        }
+
+        for (index, (path, error)) in self.failures.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}: {}", path.display(), error)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for FileSetError {}
+
+/// A target consisting of an explicit set of files and directories,
+/// e.g. the auxiliary files of a document.
+/// The files will be removed as a group: all files are staged for removal first,
+/// if any file can not be staged the staged files will be restored.
+#[derive(Debug)]
+pub struct FileSetTarget {
+    name: String,
+    path: PathBuf,
+    files: Vec<PathBuf>,
+}
+
+impl FileSetTarget {
+    /// Create a file set identified by the path, e.g. the file the set belongs to.
+    /// Note: The path itself is not part of the set unless contained within the files.
+    pub fn new(name: impl Into<String>, path: PathBuf, files: Vec<PathBuf>) -> Self {
+        Self {
+            name: name.into(),
+            path,
+            files,
+        }
+    }
+
+    fn staged_path(file: &Path) -> Option<PathBuf> {
+        let mut file_name = file.file_name()?.to_owned();
+        file_name.push(STAGED_SUFFIX);
+        Some(file.with_file_name(file_name))
+    }
+
+    /// Rename all files so they can be removed without leaving a partial set behind.
+    /// Returns the original and the staged path of every file.
+    fn stage_files(&self) -> Result<Vec<(&Path, PathBuf)>, FileSetError> {
+        let mut staged_files = Vec::with_capacity(self.files.len());
+        for file in &self.files {
+            let result = match Self::staged_path(file) {
+                Some(staged_path) => fs::rename(file, &staged_path).map(|_| staged_path),
+                None => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "path has no file name",
+                )),
+            };
+
+            match result {
+                Ok(staged_path) => staged_files.push((file.as_path(), staged_path)),
+                /* the file has already been removed */
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                Err(error) => {
+                    let mut failures = vec![(file.clone(), error)];
+                    for (original_path, staged_path) in staged_files {
+                        if let Err(error) = fs::rename(&staged_path, original_path) {
+                            failures.push((staged_path, error));
+                        }
+                    }
+
+                    return Err(FileSetError {
+                        rolled_back: true,
+                        failures,
+                    });
+                }
+            }
+        }
+
+        Ok(staged_files)
+    }
+}
+
+impl SweepableTarget for FileSetTarget {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    fn estimated_size(&self) -> Box<SizeEstimator> {
+        let mut size_total = 0;
+        let mut files = self.files.clone().into_iter();
+        let mut current_dir: Option<Box<dyn Iterator<Item = u64> + Send + Sync>> = None;
+        let mut current_dir_size = 0;
+
+        Box::new(iter::from_fn(move || loop {
+            if let Some(dir_sizes) = &mut current_dir {
+                match dir_sizes.next() {
+                    Some(dir_size) => {
+                        current_dir_size = dir_size;
+                        return Some(size_total + current_dir_size);
+                    }
+                    None => {
+                        size_total += current_dir_size;
+                        current_dir_size = 0;
+                        current_dir = None;
+                    }
+                }
+            }
+
+            let file = files.next()?;
+            let Ok(metadata) = fs::symlink_metadata(&file) else {
+                continue;
+            };
+
+            if metadata.is_dir() {
+                current_dir = Some(Box::new(estimate_size_async(file)));
+                continue;
+            }
+
+            size_total += metadata.len();
+            return Some(size_total);
+        }))
+    }
+
+    fn cleanup(&mut self, dry_run: bool) -> Result<CleanupResult, SweeperError> {
+        let size_total = self.estimated_size().last();
+        let result = CleanupResult {
+            bytes_erased: size_total,
+        };
+        if dry_run {
+            return Ok(result);
+        }
+
+        let staged_files = self.stage_files()?;
+
+        let mut failures = Vec::new();
+        for (_, staged_file) in staged_files {
+            let removed = match fs::symlink_metadata(&staged_file) {
+                Ok(metadata) if metadata.is_dir() => fs::remove_dir_all(&staged_file),
+                Ok(_) => fs::remove_file(&staged_file),
+                Err(error) => Err(error),
+            };
+
+            if let Err(error) = removed {
+                failures.push((staged_file, error));
+            }
+        }
+
+        if !failures.is_empty() {
+            return Err(FileSetError {
+                rolled_back: false,
+                failures,
+            }
+            .into());
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,58 @@
+use std::{
+    fmt::Debug,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use crate::SweeperError;
+
+pub type SizeEstimator = dyn Iterator<Item = u64> + Send + Sync;
+
+#[derive(Debug)]
+pub struct CleanupResult {
+    pub bytes_erased: Option<u64>,
+}
+
+pub trait SweepableTarget: Send + Debug {
+    fn name(&self) -> &str;
+    fn path(&self) -> &Path;
+
+    /// Global targets do not belong to any project, e.g. the caches of a toolchain.
+    fn is_global(&self) -> bool {
+        false
+    }
+
+    /// Files and directories of targets which consist of an explicit set of files.
+    /// Empty for targets which are a single directory.
+    fn files(&self) -> &[PathBuf] {
+        &[]
+    }
+
+    /// Risky targets may contain data which can not be restored by a rebuild, e.g. user configuration.
+    fn is_risky(&self) -> bool {
+        false
+    }
+
+    /// Targets which get trimmed instead of removed return the size they will be trimmed to.
+    /// The estimated size of such targets is the size reclaimable above the limit.
+    fn size_limit(&self) -> Option<u64> {
+        None
+    }
+
+    fn estimated_size(&self) -> Box<SizeEstimator>;
+    fn cleanup(&mut self, dry_run: bool) -> Result<CleanupResult, SweeperError>;
+}
+
+mod directory;
+pub use directory::*;
+
+mod cache;
+pub use cache::*;
+
+mod trim;
+pub use trim::*;
+
+mod file_set;
+pub use file_set::*;
@@ -0,0 +1,152 @@
+use std::{
+    fs,
+    iter,
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::SystemTime,
+};
+
+use super::{
+    CleanupResult,
+    SizeEstimator,
+    SweepableTarget,
+};
+use crate::{
+    fs::{
+        DirWalker,
+        EntryKind,
+    },
+    SweeperError,
+};
+
+/// Test if the file is an evictable cache entry.
+/// The path is relative to the cache directory.
+pub type CacheEntryFilter = fn(&Path) -> bool;
+
+/// A cache directory which will be trimmed to a size limit
+/// by evicting the least recently used entries instead of being removed.
+/// The estimated size is the size reclaimable above the limit.
+#[derive(Debug)]
+pub struct TrimCacheTarget {
+    name: String,
+    cache_dir: PathBuf,
+    size_limit: u64,
+    is_cache_entry: CacheEntryFilter,
+}
+
+impl TrimCacheTarget {
+    pub fn new(
+        name: impl Into<String>,
+        cache_dir: PathBuf,
+        size_limit: u64,
+        is_cache_entry: CacheEntryFilter,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            cache_dir,
+            size_limit,
+            is_cache_entry,
+        }
+    }
+
+    /// Iterate all cache entries with their size and last modification time.
+    /// Cache tools update the modification time of an entry on a cache hit.
+    fn cache_entries(&self) -> impl Iterator<Item = (PathBuf, u64, SystemTime)> {
+        let cache_dir = self.cache_dir.clone();
+        let is_cache_entry = self.is_cache_entry;
+
+        let mut walker = DirWalker::new();
+        let _ = walker.insert_entries(&cache_dir);
+        iter::from_fn(move || {
+            while let Some(entry) = walker.next_item() {
+                match entry.kind() {
+                    EntryKind::File => {
+                        let is_entry = entry
+                            .path()
+                            .strip_prefix(&cache_dir)
+                            .is_ok_and(is_cache_entry);
+                        if !is_entry {
+                            continue;
+                        }
+
+                        let Ok(metadata) = fs::symlink_metadata(entry.path()) else {
+                            continue;
+                        };
+                        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                        return Some((entry.path().to_owned(), metadata.len(), modified));
+                    }
+                    EntryKind::Directory => {
+                        let _ = walker.insert_entries(entry.path());
+                    }
+                    EntryKind::Symlink | EntryKind::Other => {}
+                }
+            }
+            None
+        })
+    }
+}
+
+impl SweepableTarget for TrimCacheTarget {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn path(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    fn is_global(&self) -> bool {
+        true
+    }
+
+    fn size_limit(&self) -> Option<u64> {
+        Some(self.size_limit)
+    }
+
+    fn estimated_size(&self) -> Box<SizeEstimator> {
+        let size_limit = self.size_limit;
+        let mut size_total = 0;
+        Box::new(self.cache_entries().map(move |(_, size, _)| {
+            size_total += size;
+            size_total.saturating_sub(size_limit)
+        }))
+    }
+
+    fn cleanup(&mut self, dry_run: bool) -> Result<CleanupResult, SweeperError> {
+        let mut entries = self.cache_entries().collect::<Vec<_>>();
+        let size_total = entries.iter().map(|(_, size, _)| size).sum::<u64>();
+        let size_reclaimable = size_total.saturating_sub(self.size_limit);
+        if dry_run {
+            return Ok(CleanupResult {
+                bytes_erased: Some(size_reclaimable),
+            });
+        }
+
+        /* evict the least recently used entries first */
+        entries.sort_by_key(|(_, _, modified)| *modified);
+
+        let mut bytes_erased = 0;
+        for (path, size, _) in entries {
+            if bytes_erased >= size_reclaimable {
+                break;
+            }
+
+            match fs::remove_file(&path) {
+                Ok(()) => bytes_erased += size,
+                Err(error) => {
+                    log::warn!(
+                        "Failed to evict cache entry {}: {:#}",
+                        path.display(),
+                        error
+                    )
+                }
+            }
+        }
+
+        Ok(CleanupResult {
+            bytes_erased: Some(bytes_erased),
+        })
+    }
+}
@@ -0,0 +1,355 @@
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    mem,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::mpsc::{
+        self,
+        RecvTimeoutError,
+    },
+    thread,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use notify::{
+    event::ModifyKind,
+    Event,
+    EventKind,
+    RecommendedWatcher,
+    RecursiveMode,
+    Watcher,
+};
+
+use crate::{
+    crew::{
+        CrewAborted,
+        CrewRun,
+    },
+    fs::{
+        entry_signature,
+        tree_signature,
+        TreeSignature,
+    },
+    CrewReport,
+};
+
+/// Interval in which the cancel state will be checked while waiting for changes
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Changes within a target will be reported at most once per interval
+const CHANGE_REPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Watching could not be continued and the crew should fall back to periodic rescans
+struct WatchUnavailable;
+
+impl CrewRun {
+    /// Keep watching the root directory for changes until the crew gets cancelled.
+    pub(crate) fn watch(&mut self, root_directory: &Path) {
+        let result = match self.watch_events(root_directory) {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(WatchUnavailable)) => {
+                log::info!(
+                    "Falling back to periodic rescans as the file system can not be watched"
+                );
+                self.watch_polling(root_directory)
+            }
+            Err(CrewAborted) => Err(CrewAborted),
+        };
+
+        if result.is_err() {
+            log::debug!("Stopped watching {}", root_directory.display());
+        }
+    }
+
+    fn watch_events(
+        &mut self,
+        root_directory: &Path,
+    ) -> Result<Result<(), WatchUnavailable>, CrewAborted> {
+        let (event_tx, event_rx) = mpsc::channel();
+        let mut watcher = match notify::recommended_watcher(event_tx) {
+            Ok(watcher) => watcher,
+            Err(error) => {
+                log::warn!("Failed to create file system watcher: {:#}", error);
+                return Ok(Err(WatchUnavailable));
+            }
+        };
+
+        /* watch all directories of the initial scan as well as all targets */
+        self.scan_found_targets
+            .extend(self.sent_targets.iter().cloned());
+        if let Err(unavailable) = self.add_watches(root_directory, &mut watcher) {
+            return Ok(Err(unavailable));
+        }
+
+        log::debug!("Watching {} for changes", root_directory.display());
+        self.report(CrewReport::Watching { polling: false });
+
+        let mut changed_targets = HashSet::new();
+        let mut last_change_report = Instant::now();
+        loop {
+            self.check_runnable()?;
+
+            match event_rx.recv_timeout(WATCH_POLL_INTERVAL) {
+                Ok(Ok(event)) => {
+                    let result = self.handle_event(
+                        root_directory,
+                        &mut watcher,
+                        event,
+                        &mut changed_targets,
+                    )?;
+                    if let Err(unavailable) = result {
+                        return Ok(Err(unavailable));
+                    }
+                }
+                Ok(Err(error)) => {
+                    log::warn!("File system watcher error: {:#}", error);
+                    if matches!(error.kind, notify::ErrorKind::MaxFilesWatch) {
+                        return Ok(Err(WatchUnavailable));
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return Ok(Err(WatchUnavailable)),
+            }
+
+            if last_change_report.elapsed() >= CHANGE_REPORT_INTERVAL {
+                for target in changed_targets.drain() {
+                    self.report(CrewReport::TargetChanged(target));
+                }
+                last_change_report = Instant::now();
+            }
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        root_directory: &Path,
+        watcher: &mut RecommendedWatcher,
+        event: Event,
+        changed_targets: &mut HashSet<PathBuf>,
+    ) -> Result<Result<(), WatchUnavailable>, CrewAborted> {
+        if matches!(event.kind, EventKind::Access(_)) {
+            return Ok(Ok(()));
+        }
+
+        let entry_added = matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(_))
+        );
+
+        for path in event.paths {
+            if let Some(target) = self.containing_target(&path) {
+                if target.exists() {
+                    changed_targets.insert(target);
+                } else {
+                    changed_targets.remove(&target);
+                    self.remove_target(&target);
+                }
+                continue;
+            }
+
+            if !path.exists() {
+                self.remove_missing_targets(&path);
+                continue;
+            }
+
+            /* new files may complete the marker files of the parent directory */
+            if let Some(parent) = path.parent().filter(|parent| *parent != root_directory) {
+                self.scan_found_targets.clear();
+                self.identify_targets(parent, None)?;
+                if let Err(unavailable) = self.add_watches(root_directory, watcher) {
+                    return Ok(Err(unavailable));
+                }
+            }
+
+            if !entry_added || !path.is_dir() || path.is_symlink() {
+                continue;
+            }
+
+            self.scan_found_targets.clear();
+            self.identify_targets(&path, None)?;
+            if let Err(unavailable) = self.add_watches(root_directory, watcher) {
+                return Ok(Err(unavailable));
+            }
+
+            if self.is_target(&path) && !self.options.search_recursively {
+                continue;
+            }
+
+            self.scan(&path, None, None)?;
+            self.scan_entered_dirs.push(path);
+            if let Err(unavailable) = self.add_watches(root_directory, watcher) {
+                return Ok(Err(unavailable));
+            }
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Watch all directories entered and targets found since the last call.
+    fn add_watches(
+        &mut self,
+        root_directory: &Path,
+        watcher: &mut RecommendedWatcher,
+    ) -> Result<(), WatchUnavailable> {
+        /* targets change their size by writes anywhere within them, e.g. target/debug/deps */
+        let targets = mem::take(&mut self.scan_found_targets)
+            .into_iter()
+            .map(|(_, path)| {
+                let mode = if self.is_shallow_target(root_directory, &path) {
+                    RecursiveMode::NonRecursive
+                } else {
+                    RecursiveMode::Recursive
+                };
+                (path, mode)
+            })
+            .collect::<Vec<_>>();
+        let target_files = self
+            .target_files
+            .iter()
+            .filter(|(_, target)| targets.iter().any(|(path, _)| path == *target))
+            .map(|(file, _)| (file.clone(), RecursiveMode::Recursive))
+            .collect::<Vec<_>>();
+        let paths = self
+            .scan_entered_dirs
+            .drain(..)
+            .map(|path| (path, RecursiveMode::NonRecursive))
+            .chain(targets)
+            .chain(target_files)
+            .collect::<HashMap<_, _>>();
+
+        for (path, mode) in paths {
+            let Err(error) = watcher.watch(&path, mode) else {
+                continue;
+            };
+
+            match error.kind {
+                notify::ErrorKind::MaxFilesWatch | notify::ErrorKind::Io(_) => {
+                    log::warn!("Failed to watch {}: {:#}", path.display(), error);
+                    return Err(WatchUnavailable);
+                }
+                _ => log::debug!("Failed to watch {}: {:#}", path.display(), error),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn watch_polling(&mut self, root_directory: &Path) -> Result<(), CrewAborted> {
+        self.report(CrewReport::Watching { polling: true });
+
+        let mut target_signatures = self
+            .target_paths
+            .iter()
+            .map(|path| (path.clone(), self.target_signature(root_directory, path)))
+            .collect::<HashMap<_, _>>();
+
+        loop {
+            let timestamp_start = Instant::now();
+            while timestamp_start.elapsed() < self.options.watch_rescan_interval {
+                self.check_runnable()?;
+                thread::sleep(WATCH_POLL_INTERVAL);
+            }
+
+            log::debug!("Rescanning {}", root_directory.display());
+            self.scan(root_directory, None, None)?;
+            self.identify_global_targets(root_directory)?;
+            self.finish_scan(root_directory)?;
+
+            let found_targets = self
+                .scan_found_targets
+                .iter()
+                .map(|(_, path)| path.clone())
+                .collect::<HashSet<_>>();
+            let removed_targets = self
+                .target_paths
+                .difference(&found_targets)
+                .cloned()
+                .collect::<Vec<_>>();
+            for target in removed_targets {
+                target_signatures.remove(&target);
+                self.remove_target(&target);
+            }
+
+            for target in found_targets {
+                let signature = self.target_signature(root_directory, &target);
+                match target_signatures.insert(target.clone(), signature) {
+                    Some(previous_signature) if previous_signature != signature => {
+                        self.report(CrewReport::TargetChanged(target))
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Global targets and targets outside of the root directory are only watched at their root.
+    /// Their contents are usually huge caches which would exhaust the available watches.
+    fn is_shallow_target(&self, root_directory: &Path, target: &Path) -> bool {
+        self.global_targets.contains(target) || !target.starts_with(root_directory)
+    }
+
+    fn target_signature(&self, root_directory: &Path, target: &Path) -> Option<TreeSignature> {
+        if self.is_shallow_target(root_directory, target) {
+            return entry_signature(target);
+        }
+
+        /* file sets change by writes within any of their files */
+        self.target_files
+            .iter()
+            .filter(|(_, file_target)| *file_target == target)
+            .filter_map(|(file, _)| tree_signature(file))
+            .fold(tree_signature(target), |signature, file_signature| {
+                Some(signature.map_or(file_signature, |signature| signature.merge(file_signature)))
+            })
+    }
+
+    /// Returns the path of the target which contains the path
+    fn containing_target(&self, path: &Path) -> Option<PathBuf> {
+        let target = self
+            .sent_targets
+            .iter()
+            .map(|(_, target)| target)
+            .find(|target| path.starts_with(target));
+        let file_target = || {
+            self.target_files
+                .iter()
+                .find(|(file, _)| path.starts_with(file))
+                .map(|(_, target)| target)
+        };
+
+        target.or_else(file_target).cloned()
+    }
+
+    /// Remove all targets within the path which do not exist any more.
+    fn remove_missing_targets(&mut self, path: &Path) {
+        let removed_targets = self
+            .sent_targets
+            .iter()
+            .map(|(_, target)| target)
+            .filter(|target| target.starts_with(path) && !target.exists())
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        for target in removed_targets {
+            self.remove_target(&target);
+        }
+    }
+
+    fn remove_target(&mut self, target: &Path) {
+        log::trace!("Target {} has been removed", target.display());
+        self.sent_targets.retain(|(_, path)| path != target);
+        self.target_paths.remove(target);
+        self.global_targets.remove(target);
+        self.target_files
+            .retain(|_, file_target| file_target != target);
+        self.report(CrewReport::TargetRemoved(target.to_owned()));
+    }
+}
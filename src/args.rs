@@ -3,11 +3,34 @@ use std::{
     path::PathBuf,
 };
 
+use anyhow::Context;
 use bclean::{
     sweeper::{
         CMakeSweeper,
+        CompilerCacheSweeper,
+        CoreDumpSweeper,
+        CustomSweeper,
+        DartSweeper,
+        DocsSweeper,
+        DotNetSweeper,
+        ElixirSweeper,
+        FrontendSweeper,
+        GameEngineSweeper,
+        GlobalCache,
+        GlobalCacheSweeper,
+        GoSweeper,
+        GradleSweeper,
+        HaskellSweeper,
+        LatexSweeper,
+        MavenSweeper,
+        MonorepoSweeper,
         NodeSweeper,
+        PythonSweeper,
         RustSweeper,
+        RustupSweeper,
+        SwiftSweeper,
+        TerraformSweeper,
+        ZigSweeper,
     },
     Sweeper,
 };
@@ -16,11 +39,37 @@ use clap::{
     ValueEnum,
 };
 
+use crate::{
+    config::Config,
+    utils,
+};
+
 #[derive(Clone, ValueEnum, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum ArgSweeper {
     CMake,
+    CompilerCache,
+    CoreDump,
+    Custom,
+    Dart,
+    Docs,
+    DotNet,
+    Elixir,
+    Frontend,
+    GameEngine,
+    GlobalCache,
+    Go,
+    Gradle,
+    Haskell,
+    Latex,
+    Maven,
+    Monorepo,
     Node,
+    Python,
     Rust,
+    Rustup,
+    Swift,
+    Terraform,
+    Zig,
 }
 
 impl ArgSweeper {
@@ -44,18 +93,111 @@ impl ArgSweeper {
             (ArgSweeper::Node, None),
             (ArgSweeper::Rust, None),
             (ArgSweeper::CMake, None),
+            (ArgSweeper::Python, None),
+            (ArgSweeper::Gradle, None),
+            (ArgSweeper::Maven, None),
+            (ArgSweeper::DotNet, None),
+            (ArgSweeper::Go, None),
+            (ArgSweeper::Haskell, None),
+            (ArgSweeper::Elixir, None),
+            (ArgSweeper::Zig, None),
+            (ArgSweeper::Swift, None),
+            (ArgSweeper::Dart, None),
+            (ArgSweeper::Frontend, None),
+            (ArgSweeper::Monorepo, None),
+            (ArgSweeper::GameEngine, None),
+            (ArgSweeper::Terraform, None),
+            (ArgSweeper::Docs, None),
+            (ArgSweeper::Latex, None),
+            (ArgSweeper::CoreDump, None),
+            (ArgSweeper::Custom, None),
         ]
     }
 
-    pub fn create_from_options(&self, _options: Option<&str>) -> anyhow::Result<Box<dyn Sweeper>> {
+    pub fn create_from_options(&self, options: Option<&str>) -> anyhow::Result<Box<dyn Sweeper>> {
         let result: Box<dyn Sweeper> = match self {
             Self::CMake => Box::new(CMakeSweeper::new()),
+            Self::CompilerCache => match options {
+                Some(size_limit) => {
+                    let size_limit = utils::parse_file_size(size_limit)
+                        .with_context(|| format!("invalid size limit {}", size_limit))?;
+                    Box::new(CompilerCacheSweeper::with_size_limit(size_limit))
+                }
+                None => Box::new(CompilerCacheSweeper::new()),
+            },
+            Self::CoreDump => Box::new(CoreDumpSweeper::new()),
+            Self::Custom => anyhow::bail!("custom sweepers are created from the config"),
+            Self::Dart => Box::new(DartSweeper::new()),
+            Self::Docs => Box::new(DocsSweeper::new()),
+            Self::DotNet => Box::new(DotNetSweeper::new()),
+            Self::Elixir => Box::new(ElixirSweeper::new()),
+            Self::Frontend => Box::new(FrontendSweeper::new()),
+            Self::GameEngine => Box::new(GameEngineSweeper::new()),
+            Self::GlobalCache => match options {
+                Some(options) => {
+                    let caches = options
+                        .split(',')
+                        .map(|name| {
+                            GlobalCache::from_name(name.trim())
+                                .with_context(|| format!("unknown global cache {}", name))
+                        })
+                        .collect::<anyhow::Result<Vec<_>>>()?;
+                    Box::new(GlobalCacheSweeper::with_caches(caches))
+                }
+                None => Box::new(GlobalCacheSweeper::new()),
+            },
+            Self::Go => Box::new(GoSweeper::new()),
+            Self::Gradle => Box::new(GradleSweeper::new()),
+            Self::Haskell => Box::new(HaskellSweeper::new()),
+            Self::Latex => Box::new(LatexSweeper::new()),
+            Self::Maven => Box::new(MavenSweeper::new()),
+            Self::Monorepo => Box::new(MonorepoSweeper::new()),
             Self::Node => Box::new(NodeSweeper::new()),
+            Self::Python => Box::new(PythonSweeper::new()),
             Self::Rust => Box::new(RustSweeper::new()),
+            Self::Rustup => Box::new(RustupSweeper::new()),
+            Self::Swift => Box::new(SwiftSweeper::new()),
+            Self::Terraform => Box::new(TerraformSweeper::new()),
+            Self::Zig => Box::new(ZigSweeper::new()),
         };
 
         Ok(result)
     }
+
+    /// Create all sweepers for the options.
+    /// The custom sweeper creates every sweeper declared in the config,
+    /// or only the selected ones with `custom=name,name`.
+    pub fn create_sweepers(
+        &self,
+        options: Option<&str>,
+        config: &Config,
+    ) -> anyhow::Result<Vec<Box<dyn Sweeper>>> {
+        if *self != Self::Custom {
+            return Ok(vec![self.create_from_options(options)?]);
+        }
+
+        let definitions = match options {
+            Some(options) => options
+                .split(',')
+                .map(|name| {
+                    config
+                        .sweepers
+                        .iter()
+                        .find(|definition| definition.name == name.trim())
+                        .with_context(|| format!("unknown custom sweeper {}", name))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            None => config.sweepers.iter().collect(),
+        };
+
+        definitions
+            .into_iter()
+            .map(|definition| {
+                let sweeper: Box<dyn Sweeper> = Box::new(CustomSweeper::new(definition.clone())?);
+                Ok(sweeper)
+            })
+            .collect()
+    }
 }
 
 /// Automate the cleanup of left over build files
@@ -76,19 +218,60 @@ pub struct Args {
     pub dry_run: bool,
 
     /// Specify a list of sweeper which should be activated.
-    /// Additionally you can specify sweeper individual arguments.
+    /// Additionally you can specify sweeper individual arguments,
+    /// which replace the default arguments of the sweeper.
     ///
     /// Available sweeper:
     /// - c-make
+    /// - core-dump
+    /// - custom (all sweepers declared in the config, select individual ones with `custom=name,name`)
+    /// - compiler-cache (not enabled by default, set the size limit with `compiler-cache=5G`, defaults to the configured cache size)
+    /// - dart
+    /// - docs
+    /// - dot-net
+    /// - elixir
+    /// - frontend
+    /// - game-engine
+    /// - global-cache (not enabled by default, select caches with `global-cache=cargo,npm,pip,yarn,pnpm,gradle,maven,go,terraform,pulumi`)
+    /// - go
+    /// - gradle
+    /// - haskell
+    /// - latex
+    /// - maven
+    /// - monorepo
     /// - rust
+    /// - rustup (not enabled by default, only reliable when scanning all projects)
+    /// - swift
+    /// - terraform
+    /// - zig
     /// - node
+    /// - python
     ///
     /// Example:
     /// -s rust -s "cmake=dirname=cmake,build,dist"
     #[arg(value_parser = ArgSweeper::parse_args, short, long, verbatim_doc_comment)]
     pub sweeper: Vec<(ArgSweeper, Option<String>)>,
 
+    /// Path of the config file declaring custom sweepers.
+    /// Defaults to `bclean/config.json` within the users config directory.
+    #[arg(short, long, verbatim_doc_comment)]
+    pub config: Option<PathBuf>,
+
     /// Do not apply the default sweeper
     #[arg(long)]
     pub sweeper_no_defaults: bool,
+
+    /// Keep watching the root directory for new, removed and changed targets
+    /// after the initial search finished.
+    #[arg(short, long)]
+    pub watch: bool,
+
+    /// File the errors which occurred while searching will be exported to.
+    /// Defaults to `bclean/errors.txt` within the users cache directory.
+    #[arg(long, verbatim_doc_comment)]
+    pub error_export: Option<PathBuf>,
+
+    /// Do not use the scan index from previous runs and do not save the results.
+    #[arg(long)]
+    pub no_index: bool,
 }
@@ -0,0 +1,52 @@
+use std::{
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use anyhow::Context;
+use bclean::sweeper::CustomSweeperDefinition;
+use serde::Deserialize;
+
+/// Settings loaded from the configuration file
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Config {
+    /// Sweepers declared by the user
+    #[serde(default)]
+    pub sweepers: Vec<CustomSweeperDefinition>,
+}
+
+impl Config {
+    pub fn default_path() -> Option<PathBuf> {
+        bclean::config_dir().map(|dir| dir.join("bclean").join("config.json"))
+    }
+
+    /// Load the configuration from the given path or from the default location.
+    /// A missing file at the default location results in an empty configuration.
+    pub fn load(path: Option<&Path>) -> anyhow::Result<Self> {
+        let (path, required) = match path {
+            Some(path) => (path.to_owned(), true),
+            None => match Self::default_path() {
+                Some(path) => (path, false),
+                None => return Ok(Self::default()),
+            },
+        };
+
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) if !required && error.kind() == io::ErrorKind::NotFound => {
+                return Ok(Self::default())
+            }
+            Err(error) => {
+                return Err(error)
+                    .with_context(|| format!("failed to read config {}", path.display()))
+            }
+        };
+
+        serde_json::from_str(&content).with_context(|| format!("invalid config {}", path.display()))
+    }
+}
@@ -1,6 +1,14 @@
 use std::{
     self,
     env,
+    path::{
+        self,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
 };
 
 use args::{
@@ -10,9 +18,11 @@ use args::{
 use bclean::{
     self,
     CrewOptions,
+    ScanIndex,
     SweeperCrew,
 };
 use clap::Parser;
+use config::Config;
 use crossterm::{
     self,
     event::{
@@ -35,6 +45,7 @@ use ui::{
 };
 
 mod args;
+mod config;
 mod term;
 mod ui;
 mod utils;
@@ -61,6 +72,15 @@ fn main() -> anyhow::Result<()> {
     };
     log::debug!("Root path: {}", root_path.display());
 
+    let config = Config::load(args.config.as_deref())?;
+
+    let error_export_path = args
+        .error_export
+        .or_else(|| bclean::cache_dir().map(|dir| dir.join("bclean").join("errors.txt")))
+        .unwrap_or_else(|| PathBuf::from("bclean-errors.txt"));
+    /* the path is displayed to the user, relative paths would depend on the working directory */
+    let error_export_path = path::absolute(&error_export_path).unwrap_or(error_export_path);
+
     let mut terminal = term::setup()?;
     terminal.clear()?;
 
@@ -69,21 +89,46 @@ fn main() -> anyhow::Result<()> {
 
         let mut sweepers = args.sweeper.clone();
         if !args.sweeper_no_defaults {
-            sweepers.extend(ArgSweeper::default_configuration());
+            /* explicitly selected sweepers replace their default configuration */
+            sweepers.extend(ArgSweeper::default_configuration().into_iter().filter(
+                |(sweeper, _)| !args.sweeper.iter().any(|(selected, _)| selected == sweeper),
+            ));
         }
 
         for (sweeper, options) in sweepers {
             log::info!("Register sweeper {:?} ({:?})", sweeper, options);
-            crew.register_boxed(sweeper.create_from_options(options.as_ref().map(String::as_str))?);
+            for sweeper in sweeper.create_sweepers(options.as_deref(), &config)? {
+                crew.register_boxed(sweeper);
+            }
         }
         crew
     };
 
+    let scan_index = if args.no_index {
+        None
+    } else {
+        match ScanIndex::open(root_path.clone()) {
+            Ok(index) => {
+                log::debug!("Loaded {} targets from scan index", index.targets().len());
+                Some(Arc::new(Mutex::new(index)))
+            }
+            Err(error) => {
+                log::warn!("Failed to open scan index: {:#}", error);
+                None
+            }
+        }
+    };
+
     let mut app_view = AppView::new(
         root_path.clone(),
         crew,
-        CrewOptions::default(),
+        CrewOptions {
+            scan_index,
+            watch: args.watch,
+            ..Default::default()
+        },
         args.dry_run,
+        error_export_path,
     );
 
     loop {
@@ -113,5 +158,6 @@ fn main() -> anyhow::Result<()> {
         }
     }
 
+    app_view.shutdown();
     Ok(())
 }
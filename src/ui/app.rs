@@ -42,9 +42,15 @@ pub enum AppView {
 }
 
 impl AppView {
-    pub fn new(root_path: PathBuf, crew: SweeperCrew, options: CrewOptions, dry_run: bool) -> Self {
+    pub fn new(
+        root_path: PathBuf,
+        crew: SweeperCrew,
+        options: CrewOptions,
+        dry_run: bool,
+        error_export_path: PathBuf,
+    ) -> Self {
         Self::TargetSelect {
-            view: SweeperWidget::new(root_path, crew, options),
+            view: SweeperWidget::new(root_path, crew, options, error_export_path),
             show_no_selection: false,
             dry_run: dry_run,
         }
@@ -57,6 +63,14 @@ impl AppView {
         }
     }
 
+    /// Cancel all background work which can be cancelled safely.
+    pub fn shutdown(self) {
+        match self {
+            Self::TargetSelect { view, .. } => view.shutdown(),
+            Self::Sweeping { .. } => {}
+        }
+    }
+
     pub fn handle_event(&mut self, event: &Event) {
         match self {
             Self::TargetSelect {
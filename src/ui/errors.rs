@@ -0,0 +1,298 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs::{
+        self,
+        File,
+    },
+    io::{
+        self,
+        BufWriter,
+        Write,
+    },
+    iter,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use crossterm::event::{
+    Event,
+    KeyCode,
+    KeyEventKind,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Stylize,
+    text::{
+        Line,
+        Span,
+    },
+    widgets::{
+        Block,
+        Borders,
+        Clear,
+        Paragraph,
+        Widget,
+    },
+};
+
+use crate::utils;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScanErrorSource {
+    FileSystem,
+    Sweeper(String),
+}
+
+impl Display for ScanErrorSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileSystem => write!(f, "file system"),
+            Self::Sweeper(name) => write!(f, "sweeper {}", name),
+        }
+    }
+}
+
+/// An error which occurred while searching for targets
+#[derive(Debug, Clone)]
+pub struct ScanError {
+    pub source: ScanErrorSource,
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl ScanError {
+    /// The directory the error will be grouped by
+    fn group_directory(&self) -> &Path {
+        self.path.parent().unwrap_or(&self.path)
+    }
+}
+
+/// Maximum number of errors which will be kept, further errors will only be counted
+const MAX_SCAN_ERRORS: usize = 10_000;
+
+/// Errors grouped by their source and parent directory as they arrive
+#[derive(Debug, Default)]
+pub struct ScanErrors {
+    groups: BTreeMap<(ScanErrorSource, PathBuf), Vec<ScanError>>,
+    kept: usize,
+    omitted: usize,
+}
+
+impl ScanErrors {
+    pub fn push(&mut self, error: ScanError) {
+        if self.kept >= MAX_SCAN_ERRORS {
+            self.omitted += 1;
+            return;
+        }
+
+        self.kept += 1;
+        self.groups
+            .entry((error.source.clone(), error.group_directory().to_owned()))
+            .or_default()
+            .push(error);
+    }
+
+    /// Number of all errors including the omitted ones
+    pub fn len(&self) -> usize {
+        self.kept + self.omitted
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn groups(&self) -> impl Iterator<Item = (&ScanErrorSource, &Path, &[ScanError])> {
+        self.groups
+            .iter()
+            .map(|((source, directory), errors)| (source, directory.as_path(), errors.as_slice()))
+    }
+
+    /// Number of lines required to display all kept errors
+    fn line_count(&self) -> usize {
+        self.groups.len() + self.kept
+    }
+}
+
+pub fn export_errors(errors: &ScanErrors, target: &Path) -> io::Result<()> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut writer = BufWriter::new(File::create(target)?);
+    for (source, directory, errors) in errors.groups() {
+        writeln!(writer, "{} ({}):", directory.display(), source)?;
+        for error in errors {
+            writeln!(writer, "  {}: {}", error.path.display(), error.message)?;
+        }
+    }
+    if errors.omitted > 0 {
+        writeln!(
+            writer,
+            "{} further errors have been omitted",
+            errors.omitted
+        )?;
+    }
+    writer.flush()
+}
+
+/// Popup listing all errors which occurred while searching for targets
+pub struct TuiScanErrorPanel {
+    scroll_offset: usize,
+    export_path: PathBuf,
+    export_status: Option<Result<PathBuf, String>>,
+}
+
+impl TuiScanErrorPanel {
+    pub fn new(export_path: PathBuf) -> Self {
+        Self {
+            scroll_offset: 0,
+            export_path,
+            export_status: None,
+        }
+    }
+
+    /// Handle the event.
+    /// Returns `false` if the panel should be closed.
+    pub fn handle_event(&mut self, event: &Event, errors: &ScanErrors) -> bool {
+        let Event::Key(event) = event else {
+            return true;
+        };
+
+        if !matches!(event.kind, KeyEventKind::Press | KeyEventKind::Repeat) {
+            return true;
+        }
+
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('e') => return false,
+            KeyCode::Down => self.scroll_offset = (self.scroll_offset + 1).min(errors.line_count()),
+            KeyCode::Up => self.scroll_offset = self.scroll_offset.saturating_sub(1),
+            KeyCode::PageUp => self.scroll_offset = 0,
+            KeyCode::Char('x') => {
+                self.export_status = Some(match export_errors(errors, &self.export_path) {
+                    Ok(_) => {
+                        log::info!(
+                            "Exported {} errors to {}",
+                            errors.len(),
+                            self.export_path.display()
+                        );
+                        Ok(self.export_path.clone())
+                    }
+                    Err(error) => {
+                        log::warn!(
+                            "Failed to export errors to {}: {:#}",
+                            self.export_path.display(),
+                            error
+                        );
+                        Err(format!("{:#}", error))
+                    }
+                });
+            }
+            _ => {}
+        }
+
+        true
+    }
+
+    pub fn render(&self, errors: &ScanErrors, area: Rect, buf: &mut Buffer) {
+        let area = utils::centered_rect(80, 80, area);
+        let view_height = area.height.saturating_sub(2) as usize;
+
+        let max_offset = errors.line_count().saturating_sub(view_height);
+        let scroll_offset = self.scroll_offset.min(max_offset);
+
+        /* only the visible lines will be created */
+        let lines = errors
+            .groups()
+            .flat_map(|(source, directory, errors)| {
+                let header = Line::from(vec![
+                    Span::raw(format!("{}", directory.display())).bold(),
+                    Span::raw(format!(" ({}, {} errors)", source, errors.len())),
+                ]);
+                let error_lines = errors.iter().map(move |error| {
+                    let file_name = error
+                        .path
+                        .strip_prefix(directory)
+                        .unwrap_or(&error.path)
+                        .display();
+                    Line::from(vec![
+                        Span::raw(format!("    ↳ {}: ", file_name)),
+                        Span::raw(error.message.clone()).red(),
+                    ])
+                });
+                iter::once(header).chain(error_lines)
+            })
+            .skip(scroll_offset)
+            .take(view_height)
+            .collect::<Vec<_>>();
+
+        let mut title = format!("Errors ({})", errors.len());
+        if errors.omitted > 0 {
+            title.push_str(&format!(", {} omitted", errors.omitted));
+        }
+        match &self.export_status {
+            None => title.push_str(&format!(
+                " - 'x' to export to {}, 'e' to close",
+                self.export_path.display()
+            )),
+            Some(Ok(path)) => title.push_str(&format!(" - exported to {}", path.display())),
+            Some(Err(error)) => title.push_str(&format!(" - export failed: {}", error)),
+        }
+
+        Clear::render(Clear, area, buf);
+        Paragraph::new(lines)
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{
+        ScanError,
+        ScanErrorSource,
+        ScanErrors,
+        MAX_SCAN_ERRORS,
+    };
+
+    fn error(path: &str) -> ScanError {
+        ScanError {
+            source: ScanErrorSource::FileSystem,
+            path: PathBuf::from(path),
+            message: "permission denied".to_string(),
+        }
+    }
+
+    #[test]
+    fn groups_errors_by_directory() {
+        let mut errors = ScanErrors::default();
+        errors.push(error("/a/x"));
+        errors.push(error("/b/x"));
+        errors.push(error("/a/y"));
+
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors.line_count(), 5);
+        let groups = errors
+            .groups()
+            .map(|(_, directory, errors)| (directory.to_owned(), errors.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(groups, [(PathBuf::from("/a"), 2), (PathBuf::from("/b"), 1)]);
+    }
+
+    #[test]
+    fn omits_errors_above_the_limit() {
+        let mut errors = ScanErrors::default();
+        for index in 0..MAX_SCAN_ERRORS + 5 {
+            errors.push(error(&format!("/a/{}", index)));
+        }
+
+        assert_eq!(errors.len(), MAX_SCAN_ERRORS + 5);
+        assert_eq!(errors.omitted, 5);
+        assert_eq!(errors.line_count(), MAX_SCAN_ERRORS + 1);
+    }
+}
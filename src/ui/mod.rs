@@ -10,6 +10,9 @@ pub use sweeper::*;
 mod sweeping;
 pub use sweeping::*;
 
+mod errors;
+pub use errors::*;
+
 mod logger;
 pub use logger::*;
 
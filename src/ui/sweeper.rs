@@ -5,7 +5,6 @@ use std::{
         Arc,
         Mutex,
     },
-    thread::JoinHandle,
     time::{
         Duration,
         Instant,
@@ -13,13 +12,19 @@ use std::{
 };
 
 use bclean::{
+    CrewHandle,
     CrewOptions,
     CrewReport,
     CrewReportConsumer,
+    ScanIndex,
     SweepableTarget,
     SweeperCrew,
 };
-use crossterm::event::Event;
+use crossterm::event::{
+    Event,
+    KeyCode,
+    KeyEventKind,
+};
 use ratatui::{
     buffer::Buffer,
     layout::{
@@ -35,12 +40,30 @@ use ratatui::{
     widgets::Widget,
 };
 
-use super::TuiSweeperTargetSelect;
+use super::{
+    ScanError,
+    ScanErrorSource,
+    ScanErrors,
+    TuiScanErrorPanel,
+    TuiSweeperTargetSelect,
+};
 use crate::utils::format_duration;
 
 #[derive(Debug, Default)]
 struct UiReportInfo {
     current_file: Option<PathBuf>,
+
+    dirs_visited: u64,
+    entries_inspected: u64,
+    errors: ScanErrors,
+
+    scan_finished: bool,
+
+    /// Set when the crew is watching for changes.
+    /// The value indicates if the crew is polling.
+    watching: Option<bool>,
+    removed_targets: Vec<PathBuf>,
+    changed_targets: Vec<PathBuf>,
 }
 struct UiReportConsumer {
     ui_info: Arc<Mutex<UiReportInfo>>,
@@ -48,13 +71,38 @@ struct UiReportConsumer {
 
 impl CrewReportConsumer for UiReportConsumer {
     fn consume_report(&mut self, report: CrewReport) {
+        let Ok(mut ui_info) = self.ui_info.lock() else {
+            return;
+        };
+
         match report {
             CrewReport::StatusInspecting(target) => {
-                let Ok(mut ui_info) = self.ui_info.lock() else {
-                    return;
-                };
                 ui_info.current_file = Some(target);
+                ui_info.entries_inspected += 1;
+            }
+            CrewReport::DirectoryEntered(_) => ui_info.dirs_visited += 1,
+            CrewReport::ErrorFs { path, error } => ui_info.errors.push(ScanError {
+                source: ScanErrorSource::FileSystem,
+                path,
+                message: format!("{:#}", error),
+            }),
+            CrewReport::ErrorSweeper {
+                sweeper,
+                path,
+                error,
+            } => ui_info.errors.push(ScanError {
+                source: ScanErrorSource::Sweeper(sweeper),
+                path,
+                message: format!("{:#}", error),
+            }),
+            CrewReport::ScanFinished { statistics, .. } => {
+                ui_info.dirs_visited = statistics.dirs_visited;
+                ui_info.entries_inspected = statistics.entries_inspected;
+                ui_info.scan_finished = true;
             }
+            CrewReport::Watching { polling } => ui_info.watching = Some(polling),
+            CrewReport::TargetRemoved(path) => ui_info.removed_targets.push(path),
+            CrewReport::TargetChanged(path) => ui_info.changed_targets.push(path),
             _ => {}
         }
     }
@@ -67,20 +115,30 @@ pub struct SweeperWidget {
     root_path: PathBuf,
 
     crew_rx: Receiver<Box<dyn SweepableTarget>>,
-    crew_handle: JoinHandle<()>,
+    crew_handle: CrewHandle,
     crew_finished: bool,
 
     target_select: TuiSweeperTargetSelect,
     report_info: Arc<Mutex<UiReportInfo>>,
+    scan_index: Option<Arc<Mutex<ScanIndex>>>,
+
+    error_panel: Option<TuiScanErrorPanel>,
+    error_export_path: PathBuf,
 }
 
 impl SweeperWidget {
-    pub fn new(root_path: PathBuf, crew: SweeperCrew, mut options: CrewOptions) -> Self {
+    pub fn new(
+        root_path: PathBuf,
+        crew: SweeperCrew,
+        mut options: CrewOptions,
+        error_export_path: PathBuf,
+    ) -> Self {
         let report_info = Arc::new(Mutex::new(UiReportInfo::default()));
         options.report_consumer = Box::new(UiReportConsumer {
             ui_info: report_info.clone(),
         });
 
+        let scan_index = options.scan_index.clone();
         let (crew_handle, crew_rx) = crew.execute(root_path.clone(), options);
         Self {
             root_path: root_path.clone(),
@@ -94,15 +152,38 @@ impl SweeperWidget {
 
             target_select: TuiSweeperTargetSelect::new(Some(root_path)),
             report_info,
+            scan_index,
+
+            error_panel: None,
+            error_export_path,
         }
     }
 
     pub fn poll(&mut self) {
         while let Ok(value) = self.crew_rx.try_recv() {
-            self.target_select.add_target(value);
+            let size_hint = self
+                .scan_index
+                .as_ref()
+                .and_then(|index| index.lock().ok()?.target_size(value.path()));
+            self.target_select
+                .add_target_with_size_hint(value, size_hint);
         }
 
-        let finished = self.crew_handle.is_finished();
+        let finished = {
+            let Ok(mut report_info) = self.report_info.lock() else {
+                return;
+            };
+
+            for path in report_info.removed_targets.drain(..) {
+                log::debug!("Removing vanished target {}", path.display());
+                self.target_select.remove_target(&path);
+            }
+            for path in report_info.changed_targets.drain(..) {
+                self.target_select.reestimate_target(&path);
+            }
+
+            report_info.scan_finished || self.crew_handle.is_finished()
+        };
         if finished == self.crew_finished {
             return;
         }
@@ -114,9 +195,66 @@ impl SweeperWidget {
     }
 
     pub fn handle_event(&mut self, event: &Event) {
+        if let Some(error_panel) = &mut self.error_panel {
+            let Ok(report_info) = self.report_info.lock() else {
+                return;
+            };
+
+            if !error_panel.handle_event(event, &report_info.errors) {
+                self.error_panel = None;
+            }
+            return;
+        }
+
+        if let Event::Key(key) = event {
+            if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('p') {
+                self.toggle_pause();
+                return;
+            }
+
+            if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('e') {
+                self.error_panel = Some(TuiScanErrorPanel::new(self.error_export_path.clone()));
+                return;
+            }
+        }
+
         self.target_select.handle_event(event);
     }
 
+    fn toggle_pause(&mut self) {
+        if self.crew_handle.is_finished() {
+            return;
+        }
+
+        if self.crew_handle.is_paused() {
+            log::debug!("Resuming sweeper crew");
+            self.crew_handle.resume();
+        } else {
+            log::debug!("Pausing sweeper crew");
+            self.crew_handle.pause();
+        }
+    }
+
+    /// Stop the sweeper crew, wait until it exited and persist the scan index.
+    pub fn shutdown(self) {
+        self.crew_handle.cancel_and_join();
+
+        let Some(scan_index) = &self.scan_index else {
+            return;
+        };
+        let Ok(mut scan_index) = scan_index.lock() else {
+            return;
+        };
+
+        for (path, size) in self.target_select.target_sizes() {
+            scan_index.update_target_size(path, size);
+        }
+
+        if let Err(error) = scan_index.save() {
+            log::warn!("Failed to save scan index: {:#}", error);
+        }
+    }
+
     pub fn selected_target_count(&self) -> usize {
         self.target_select.selected_target_count()
     }
@@ -143,7 +281,37 @@ impl Widget for &SweeperWidget {
             let mut line_segments = Vec::with_capacity(8);
             line_segments.push(Span::raw(format_duration(&time_elapsed)));
 
-            if self.crew_finished {
+            if let Ok(report_info) = self.report_info.lock() {
+                line_segments.push(Span::raw(format!(
+                    " [{} dirs | {} entries | ",
+                    report_info.dirs_visited, report_info.entries_inspected
+                )));
+                let errors = Span::raw(format!("{} errors", report_info.errors.len()));
+                line_segments.push(if !report_info.errors.is_empty() {
+                    errors.red()
+                } else {
+                    errors
+                });
+                line_segments.push("]".into());
+            }
+
+            let watching = self
+                .report_info
+                .lock()
+                .ok()
+                .and_then(|report_info| report_info.watching);
+
+            if self.crew_handle.is_paused() && watching.is_some() {
+                line_segments.push(Span::raw(format!(
+                    " Paused watching {}",
+                    self.root_path.display()
+                )));
+            } else if let Some(polling) = watching {
+                line_segments.push(Span::raw(format!(" Watching {}", self.root_path.display())));
+                if polling {
+                    line_segments.push(" (rescanning periodically)".italic());
+                }
+            } else if self.crew_finished {
                 line_segments.push(Span::raw(format!(" Finished {}", self.root_path.display())));
             } else {
                 let current_path = self
@@ -154,7 +322,11 @@ impl Widget for &SweeperWidget {
                     .flatten()
                     .unwrap_or_else(|| self.root_path.clone());
 
-                line_segments.push(" Searching ".into());
+                if self.crew_handle.is_paused() {
+                    line_segments.push(" Paused ".into());
+                } else {
+                    line_segments.push(" Searching ".into());
+                }
                 if let Ok(path) = current_path.strip_prefix(&self.root_path) {
                     line_segments.push(Span::raw(format!("{}", self.root_path.join("").display())));
                     line_segments.push(Span::raw(format!("{}", path.display())).italic());
@@ -173,5 +345,11 @@ impl Widget for &SweeperWidget {
 
         self.target_select.render(layout[0], buf);
         footer.render(layout[1], buf);
+
+        if let Some(error_panel) = &self.error_panel {
+            if let Ok(report_info) = self.report_info.lock() {
+                error_panel.render(&report_info.errors, area, buf);
+            }
+        }
     }
 }
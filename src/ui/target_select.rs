@@ -57,20 +57,69 @@ use ratatui::{
 use super::ScrollableText;
 use crate::utils;
 
+/// Size of a target which has not been estimated yet
+const SIZE_UNKNOWN: i64 = i64::MIN;
+
+enum TargetSize {
+    Unknown,
+    Estimating(u64),
+    Final(u64),
+}
+
+impl TargetSize {
+    /// Sizes being estimated are stored negative and offset by one
+    /// to distinguish them from a final size of zero.
+    fn store_estimating(target: &AtomicI64, size: u64) {
+        target.store(-(size as i64) - 1, Ordering::Relaxed);
+    }
+
+    fn load(target: &AtomicI64) -> Self {
+        match target.load(Ordering::Relaxed) {
+            SIZE_UNKNOWN => Self::Unknown,
+            size if size < 0 => Self::Estimating((-(size + 1)) as u64),
+            size => Self::Final(size as u64),
+        }
+    }
+}
+
 struct TuiTargetSelectState {
     _target_id: u32,
     target: Box<dyn SweepableTarget>,
     size: Arc<AtomicI64>,
+
+    /// Size known from a previous run which is displayed until the target has been fully sized
+    size_hint: Option<u64>,
+
     selected: bool,
 
+    /// Display the files of the target below the target
+    expanded: bool,
+
     ui_path: ScrollableText,
 }
 
+impl TuiTargetSelectState {
+    /// Returns the fully estimated size, otherwise the size hint or the size estimated so far.
+    fn display_size(&self) -> u64 {
+        match (TargetSize::load(&self.size), self.size_hint) {
+            (TargetSize::Final(size), _) => size,
+            (_, Some(size_hint)) => size_hint,
+            (TargetSize::Estimating(size), None) => size,
+            (TargetSize::Unknown, None) => 0,
+        }
+    }
+}
+
+/// Targets are ordered by their key which places all global targets
+/// in a separate section after the project targets.
+type TargetKey = (bool, u32);
+
 pub struct TuiSweeperTargetSelect {
     target_id_index: u32,
-    targets: BTreeMap<u32, TuiTargetSelectState>,
+    targets: BTreeMap<TargetKey, TuiTargetSelectState>,
 
     cursor_current: usize,
+    /// Offset of the view in rendered rows
     view_offset: usize,
     view_height: RefCell<usize>,
 
@@ -88,9 +137,7 @@ impl TuiSweeperTargetSelect {
         let estimate_handle = thread::spawn(move || {
             while let Ok((estimator, target_value)) = estimate_rx.recv() {
                 let value = estimator
-                    .inspect(|value| {
-                        target_value.store(-(*value as i64), Ordering::Relaxed);
-                    })
+                    .inspect(|value| TargetSize::store_estimating(&target_value, *value))
                     .last()
                     .unwrap_or(0);
 
@@ -116,6 +163,16 @@ impl TuiSweeperTargetSelect {
     }
 
     pub fn add_target(&mut self, target: Box<dyn SweepableTarget>) {
+        self.add_target_with_size_hint(target, None)
+    }
+
+    /// Add a target with a previously known size which will be displayed
+    /// as estimate until the target has been sized again.
+    pub fn add_target_with_size_hint(
+        &mut self,
+        target: Box<dyn SweepableTarget>,
+        size_hint: Option<u64>,
+    ) {
         self.target_id_index += 1;
         let target_id = self.target_id_index;
 
@@ -128,6 +185,14 @@ impl TuiSweeperTargetSelect {
         } else {
             format!("{}", target.path().display())
         };
+        let path_text = match target.size_limit() {
+            Some(size_limit) => format!(
+                "{} (reclaimable above {})",
+                path_text,
+                utils::format_file_size(size_limit)
+            ),
+            None => path_text,
+        };
 
         let target = TuiTargetSelectState {
             ui_path: ScrollableText::new(path_text),
@@ -136,14 +201,60 @@ impl TuiSweeperTargetSelect {
             target,
 
             selected: false,
-            size: Default::default(),
+            expanded: false,
+            size: Arc::new(AtomicI64::new(SIZE_UNKNOWN)),
+            size_hint,
         };
 
         if let Some(tx) = &self.estimate_tx {
             let _ = tx.send((target.target.estimated_size(), target.size.clone()));
         }
 
-        self.targets.insert(target_id, target);
+        self.targets
+            .insert((target.target.is_global(), target_id), target);
+    }
+
+    pub fn remove_target(&mut self, path: &Path) {
+        self.targets
+            .retain(|_, target| target.target.path() != path);
+
+        self.cursor_current = self
+            .cursor_current
+            .min(self.targets.len().saturating_sub(1));
+        self.scroll_to_cursor();
+    }
+
+    /// Estimate the size of the target again.
+    /// The current size will be displayed as estimate until the new size is known.
+    pub fn reestimate_target(&mut self, path: &Path) {
+        let Some(tx) = &self.estimate_tx else {
+            return;
+        };
+
+        for target in self
+            .targets
+            .values_mut()
+            .filter(|target| target.target.path() == path)
+        {
+            /* the target changed, the size of a previous run is outdated */
+            target.size_hint = None;
+            if let TargetSize::Final(size) | TargetSize::Estimating(size) =
+                TargetSize::load(&target.size)
+            {
+                TargetSize::store_estimating(&target.size, size);
+            }
+            let _ = tx.send((target.target.estimated_size(), target.size.clone()));
+        }
+    }
+
+    /// Returns the path and size of all targets which have been fully sized.
+    pub fn target_sizes(&self) -> impl Iterator<Item = (&Path, u64)> {
+        self.targets
+            .values()
+            .filter_map(|target| match TargetSize::load(&target.size) {
+                TargetSize::Final(size) => Some((target.target.path(), size)),
+                TargetSize::Unknown | TargetSize::Estimating(_) => None,
+            })
     }
 
     pub fn selected_target_count(&self) -> usize {
@@ -164,12 +275,12 @@ impl TuiSweeperTargetSelect {
             .targets
             .iter()
             .filter(|(_, target)| target.selected)
-            .map(|(target_id, _)| *target_id)
+            .map(|(target_key, _)| *target_key)
             .collect::<Vec<_>>();
 
         let mut removed_targets = Vec::new();
-        for target_id in selected_targets {
-            let Some(target) = self.targets.remove(&target_id) else {
+        for target_key in selected_targets {
+            let Some(target) = self.targets.remove(&target_key) else {
                 continue;
             };
             removed_targets.push(target.target);
@@ -178,12 +289,12 @@ impl TuiSweeperTargetSelect {
     }
 
     fn cursor_target_mut(&mut self) -> Option<&mut TuiTargetSelectState> {
-        self.cursor_target_id()
-            .map(|target_id| self.targets.get_mut(&target_id))
+        self.cursor_target_key()
+            .map(|target_key| self.targets.get_mut(&target_key))
             .flatten()
     }
 
-    fn cursor_target_id(&self) -> Option<u32> {
+    fn cursor_target_key(&self) -> Option<TargetKey> {
         self.targets
             .keys()
             .skip(self.cursor_current)
@@ -195,10 +306,17 @@ impl TuiSweeperTargetSelect {
         let Event::Key(event) = event else { return };
         if event.code == KeyCode::Char(' ') && event.kind == KeyEventKind::Press {
             if self.select_all {
+                /* risky targets have to be selected explicitly */
                 let selected = self
-                    .cursor_target_mut()
-                    .map_or(false, |target| target.selected);
-                for target in self.targets.values_mut() {
+                    .targets
+                    .values()
+                    .filter(|target| !target.target.is_risky())
+                    .all(|target| target.selected);
+                for target in self
+                    .targets
+                    .values_mut()
+                    .filter(|target| !target.target.is_risky())
+                {
                     target.selected = !selected;
                 }
             } else {
@@ -233,20 +351,65 @@ impl TuiSweeperTargetSelect {
         if event.code == KeyCode::Char('a') && event.kind == KeyEventKind::Press {
             self.select_all = !self.select_all;
         }
+
+        if matches!(event.code, KeyCode::Right | KeyCode::Left) && event.kind == KeyEventKind::Press
+        {
+            let expanded = event.code == KeyCode::Right;
+            if let Some(target) = self.cursor_target_mut() {
+                target.expanded = expanded && !target.target.files().is_empty();
+            }
+            self.scroll_to_cursor();
+        }
     }
 
-    fn set_cursor_index(&mut self, index: usize) {
-        let index = index.clamp(0, self.targets.len() - 1);
+    /// Returns the first rendered row and the number of rendered rows of every target.
+    /// The header of the global section and the expanded files belong to the rows of their target.
+    fn target_rows(&self) -> Vec<(usize, usize)> {
+        let mut target_rows = Vec::with_capacity(self.targets.len());
+        let mut row = 0;
+        let mut global_section_started = false;
+        for ((global, _), target) in &self.targets {
+            let mut row_count = 1;
+            if *global && !global_section_started {
+                global_section_started = true;
+                row_count += 1;
+            }
+            if target.expanded {
+                row_count += target.target.files().len();
+            }
+
+            target_rows.push((row, row_count));
+            row += row_count;
+        }
+        target_rows
+    }
+
+    /// Move the view so all rows of the cursor target are visible if possible.
+    fn scroll_to_cursor(&mut self) {
+        let Some(&(row_start, row_count)) = self.target_rows().get(self.cursor_current) else {
+            self.view_offset = 0;
+            return;
+        };
+        /* the table header takes up one row */
         let view_height = *self.view_height.borrow() - 1;
 
-        if index >= self.view_offset + view_height {
-            self.view_offset = index - view_height + 1;
+        let row_end = row_start + row_count;
+        if row_end > self.view_offset + view_height {
+            self.view_offset = row_end.saturating_sub(view_height).min(row_start);
         }
-        if index < self.view_offset + 1 {
-            self.view_offset = if index > 0 { index - 1 } else { 0 };
+        if row_start < self.view_offset + 1 {
+            self.view_offset = row_start.saturating_sub(1);
         }
+    }
+
+    fn set_cursor_index(&mut self, index: usize) {
+        if self.targets.is_empty() {
+            return;
+        }
+        let index = index.clamp(0, self.targets.len() - 1);
 
         self.cursor_current = index;
+        self.scroll_to_cursor();
         self.select_all = false;
         if let Some(target) = self.cursor_target_mut() {
             target.ui_path.reset_scroll();
@@ -260,10 +423,12 @@ impl Widget for &TuiSweeperTargetSelect {
         Self: Sized,
     {
         let layout = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).split(area);
-        *self.view_height.borrow_mut() = layout[0].height as usize;
+        *self.view_height.borrow_mut() = (layout[0].height as usize).max(2);
+        /* the table header takes up one row */
+        let view_height = layout[0].height.saturating_sub(1) as usize;
 
         let content = {
-            let mut rows = Vec::with_capacity(layout[0].height as usize);
+            let mut rows = Vec::with_capacity(view_height);
 
             let width_layout = Layout::horizontal([
                 Constraint::Length(16), // Checkbox + space + size + space
@@ -274,17 +439,50 @@ impl Widget for &TuiSweeperTargetSelect {
 
             let max_path_text_width = width_layout[1].width as usize;
 
-            for (index, target) in self.targets.values().enumerate().skip(self.view_offset) {
-                let target_size = target.size.load(Ordering::Relaxed);
+            /* rows of the first visible target which are scrolled out of the view */
+            let mut skip_rows = 0;
+            let mut global_section_started = false;
+            for (index, (((global, _), target), (row_start, row_count))) in
+                self.targets.iter().zip(self.target_rows()).enumerate()
+            {
+                if row_start >= self.view_offset + view_height {
+                    break;
+                }
+                let global_section_start = *global && !global_section_started;
+                global_section_started |= *global;
+                if row_start + row_count <= self.view_offset {
+                    continue;
+                }
+                if rows.is_empty() {
+                    skip_rows = self.view_offset - row_start;
+                }
+
+                if global_section_start {
+                    rows.push(Row::new(vec![
+                        Cell::new(""),
+                        Cell::new(""),
+                        Cell::new(Span::raw("Global caches").bold()),
+                        Cell::new(""),
+                    ]));
+                }
+
                 let target_selected = if target.selected { "X" } else { " " };
 
-                let target_size = if target_size > 0 {
-                    utils::format_file_size(target_size as u64).into()
-                } else if target_size < 0 {
+                let target_size = match (TargetSize::load(&target.size), target.size_hint) {
+                    /* trimmed targets have nothing to reclaim */
+                    (TargetSize::Final(0), _) if target.target.size_limit().is_some() => {
+                        "within limit".into()
+                    }
+                    (TargetSize::Final(size), _) => utils::format_file_size(size).into(),
+                    /* size of a previous run, the target may have changed since */
+                    (_, Some(size_hint)) => {
+                        Span::raw(format!("~{}", utils::format_file_size(size_hint))).italic()
+                    }
                     /* estimate */
-                    Span::raw(utils::format_file_size(target_size.abs() as u64)).italic()
-                } else {
-                    "waiting".into()
+                    (TargetSize::Estimating(size), None) => {
+                        Span::raw(utils::format_file_size(size)).italic()
+                    }
+                    (TargetSize::Unknown, None) => "waiting".into(),
                 };
 
                 let row = Row::new(vec![
@@ -295,19 +493,40 @@ impl Widget for &TuiSweeperTargetSelect {
                     } else {
                         target.ui_path.fixed_value(max_path_text_width)
                     }),
-                    Cell::new(target.target.name()),
+                    Cell::new(if target.target.is_risky() {
+                        Span::raw(format!("{}!", target.target.name())).red()
+                    } else {
+                        Span::raw(target.target.name())
+                    }),
                 ]);
-                let row = if index == self.cursor_current || self.select_all {
+                let row = if index == self.cursor_current
+                    || (self.select_all && !target.target.is_risky())
+                {
                     row.on_gray()
                 } else {
                     row
                 };
 
                 rows.push(row);
+
+                if target.expanded {
+                    let base_path = target.target.path().parent();
+                    for file in target.target.files() {
+                        let file = base_path
+                            .and_then(|base_path| file.strip_prefix(base_path).ok())
+                            .unwrap_or(file);
+                        rows.push(Row::new(vec![
+                            Cell::new(""),
+                            Cell::new(""),
+                            Cell::new(Span::raw(format!("  ↳ {}", file.display())).dim()),
+                            Cell::new(""),
+                        ]));
+                    }
+                }
             }
 
             Table::new(
-                rows,
+                rows.into_iter().skip(skip_rows).take(view_height),
                 &[
                     Constraint::Length(3),
                     Constraint::Length(12),
@@ -327,7 +546,7 @@ impl Widget for &TuiSweeperTargetSelect {
             let size_total = self
                 .targets
                 .values()
-                .map(|target| target.size.load(Ordering::Relaxed).abs() as u64)
+                .map(TuiTargetSelectState::display_size)
                 .sum::<u64>();
 
             let count_selected = self
@@ -339,7 +558,7 @@ impl Widget for &TuiSweeperTargetSelect {
                 .targets
                 .values()
                 .filter(|target| target.selected)
-                .map(|target| target.size.load(Ordering::Relaxed).abs() as u64)
+                .map(TuiTargetSelectState::display_size)
                 .sum::<u64>();
 
             let text_total = Span::raw(format!(
@@ -67,3 +67,51 @@ pub fn format_file_size(size: u64) -> String {
         format!("{} bytes", size)
     }
 }
+
+/// Parse a size like `512M`, `2G` or `1024` (bytes).
+pub fn parse_file_size(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let digits = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (size, unit) = value.split_at(digits);
+
+    let unit = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" => SIZE_1KB,
+        "M" | "MB" => SIZE_1MB,
+        "G" | "GB" => SIZE_1GB,
+        _ => return None,
+    };
+    size.parse::<u64>().ok()?.checked_mul(unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parse_file_size,
+        SIZE_1GB,
+        SIZE_1KB,
+        SIZE_1MB,
+    };
+
+    #[test]
+    fn parses_file_sizes() {
+        assert_eq!(parse_file_size("512"), Some(512));
+        assert_eq!(parse_file_size("512B"), Some(512));
+        assert_eq!(parse_file_size("4k"), Some(4 * SIZE_1KB));
+        assert_eq!(parse_file_size("20 MB"), Some(20 * SIZE_1MB));
+        assert_eq!(parse_file_size(" 5G "), Some(5 * SIZE_1GB));
+    }
+
+    #[test]
+    fn rejects_invalid_file_sizes() {
+        assert_eq!(parse_file_size(""), None);
+        assert_eq!(parse_file_size("G"), None);
+        assert_eq!(parse_file_size("1.5G"), None);
+        assert_eq!(parse_file_size("-1G"), None);
+        assert_eq!(parse_file_size("5T"), None);
+        assert_eq!(parse_file_size("99999999999999999999G"), None);
+        assert_eq!(parse_file_size("18446744073709551615G"), None);
+    }
+}